@@ -0,0 +1,131 @@
+use super::{
+    config::WIN_W,
+    font::{draw_text, Align},
+    geometry::Rect,
+    map::Map,
+};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// A game through every level with a limited number of lives, which ends when they run out.
+pub struct Arcade {
+    /// Lives left, including the current one.
+    lives: u32,
+
+    /// Points scored in the completed levels.
+    points: u32,
+
+    /// Points at which the next extra life is earned.
+    next: u32,
+
+    /// Levels completed.
+    levels: usize,
+
+    /// The bodies of the extra life pickups collected in the level being played, which don't come back when it's
+    /// reset.
+    collected: Vec<Rect>,
+}
+
+impl Arcade {
+    /// Lives at the start of a game.
+    const LIVES: u32 = 3;
+
+    /// Most lives that can be held at once.
+    const MAX_LIVES: u32 = 9;
+
+    /// Points needed for each extra life.
+    const EXTRA: u32 = 3000;
+
+    /// Returns a new game from the first level.
+    pub fn new() -> Self {
+        Self {
+            lives: Self::LIVES,
+            points: 0,
+            next: Self::EXTRA,
+            levels: 0,
+            collected: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the level being played.
+    pub fn get_level(&self) -> usize {
+        self.levels
+    }
+
+    /// Returns the lives left, including the current one.
+    pub fn get_lives(&self) -> u32 {
+        self.lives
+    }
+
+    /// Returns the points scored so far.
+    pub fn get_points(&self) -> u32 {
+        self.points
+    }
+
+    /// Returns true if every level was completed, else false.
+    pub fn is_done(&self) -> bool {
+        self.levels == Map::N
+    }
+
+    /// Returns true if no lives are left, else false.
+    pub fn is_over(&self) -> bool {
+        self.lives == 0
+    }
+
+    /// Loses a life.
+    pub fn lose_life(&mut self) {
+        self.lives = self.lives.saturating_sub(1);
+    }
+
+    /// Collects the extra life pickup with the given body, gaining a life.
+    pub fn collect_life(&mut self, b: Rect) {
+        self.collected.push(b);
+        self.gain_lives(1);
+    }
+
+    /// Returns true if the extra life pickup with the given body was collected in the level being played, else false.
+    pub fn is_collected(&self, b: &Rect) -> bool {
+        self.collected.contains(b)
+    }
+
+    /// Gains the given number of lives, up to the max.
+    pub fn gain_lives(&mut self, n: u32) {
+        self.lives = (self.lives + n).min(Self::MAX_LIVES);
+    }
+
+    /// Completes the level being played, scoring the given points and any extra lives they earn.
+    pub fn complete(&mut self, points: u32) {
+        self.points += points;
+        while self.points >= self.next {
+            self.next += Self::EXTRA;
+            self.gain_lives(1);
+        }
+        self.levels += 1;
+        self.collected.clear();
+    }
+
+    /// Draws the lives left and the points scored.
+    pub fn draw(&self, cnv: &mut Canvas<Window>) {
+        let s = format!("LIVES {}  SCORE {}", self.get_lives(), self.points);
+        let cx = WIN_W as f32 / 2.0;
+        draw_text(cnv, &s, cx, 10.0, 3.0, Color::WHITE, Align::Center);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn earns_extra_lives() {
+        let mut a = Arcade::new();
+        a.lose_life();
+        a.complete(Arcade::EXTRA * 2 + 1);
+        assert_eq!(a.lives, Arcade::LIVES + 1);
+        assert_eq!(a.get_level(), 1);
+
+        a.gain_lives(100);
+        assert_eq!(a.lives, Arcade::MAX_LIVES);
+        (0..Arcade::MAX_LIVES).for_each(|_| a.lose_life());
+        assert!(a.is_over());
+    }
+}
@@ -0,0 +1,107 @@
+use super::{
+    enemies::EnemyVec,
+    geometry::{BBox, Vec2},
+    player::Player,
+};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// What happens to the enemies killed since a checkpoint was reached, when the player respawns at it.
+#[derive(Clone, Copy, Debug)]
+pub enum Respawn {
+    Restore, // They come back, and every enemy is back where it was when the checkpoint was reached.
+    Keep,    // They stay dead, and the enemies left stay where they are.
+}
+
+/// A checkpoint the player reached, along with the state of the level at that time.
+pub struct Checkpoint {
+    /// The checkpoint's bounding-box.
+    b: BBox,
+
+    /// Copies of the enemies left when the checkpoint was reached.
+    e: EnemyVec,
+
+    /// The player's kills and combo kills when the checkpoint was reached.
+    kills: (u32, u32),
+}
+
+impl Checkpoint {
+    /// Color drawn over a reached checkpoint.
+    const COLOR: Color = Color {
+        r: 255,
+        g: 240,
+        b: 120,
+        a: 90,
+    };
+
+    /// Returns a new checkpoint with the given bounding-box, reached with the given player and enemies.
+    pub fn new(b: BBox, p: &Player, e: &EnemyVec) -> Self {
+        Self {
+            b,
+            e: e.iter().filter_map(|e| e.duplicate()).collect(),
+            kills: p.get_kills(),
+        }
+    }
+
+    /// Returns true if the checkpoint has the given bounding-box, else false.
+    pub fn is_at(&self, b: &BBox) -> bool {
+        self.b == *b
+    }
+
+    /// Respawns the player standing in the checkpoint, handling the enemies killed since it was reached.
+    pub fn respawn(&self, r: Respawn, p: &mut Player, e: &mut EnemyVec) {
+        let kills = match r {
+            Respawn::Restore => {
+                *e = self.e.iter().filter_map(|e| e.duplicate()).collect();
+                self.kills
+            }
+            Respawn::Keep => p.get_kills(),
+        };
+
+        let pos = Vec2::new(self.b.center().x - Player::S / 2.0, self.b.b - Player::S);
+        *p = p.respawned(pos, kills);
+    }
+
+    /// Draws the checkpoint lit up.
+    pub fn draw(&self, cnv: &mut Canvas<Window>) {
+        cnv.set_draw_color(Self::COLOR);
+        cnv.fill_rect(&self.b).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enemies::Sitter, energy::Energy, entity::Entity, keys::Keys};
+
+    #[test]
+    fn respawns_inside() {
+        // The checkpoint on THE HORDE.
+        let b = BBox::new(300.0, 500.0, 350.0, 550.0);
+        let mut p = Player::new(Vec2::new(600.0, 100.0), Energy::DEFAULT, Keys::DEFAULT);
+        let mut e = EnemyVec::new();
+        let c = Checkpoint::new(b, &p, &e);
+
+        for r in [Respawn::Restore, Respawn::Keep] {
+            c.respawn(r, &mut p, &mut e);
+            let body = p.get_body();
+            assert!(b.x <= body.x && body.x + body.w <= b.a);
+            assert_eq!(body.y + body.h, b.b);
+        }
+    }
+
+    #[test]
+    fn restores_killed_enemies() {
+        let b = BBox::new(300.0, 500.0, 350.0, 550.0);
+        let mut p = Player::new(Vec2::new(600.0, 100.0), Energy::DEFAULT, Keys::DEFAULT);
+        let mut e: EnemyVec = vec![Box::new(Sitter::new(50.0, 40.0))];
+        let c = Checkpoint::new(b, &p, &e);
+
+        e[0].kill();
+        c.respawn(Respawn::Keep, &mut p, &mut e);
+        assert!(!e[0].is_alive());
+
+        c.respawn(Respawn::Restore, &mut p, &mut e);
+        assert_eq!(e.len(), 1);
+        assert!(e[0].is_alive());
+    }
+}
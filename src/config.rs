@@ -1,4 +1,4 @@
-use super::geometry::BBox;
+use super::geometry::{BBox, Vec2};
 
 /// Window width in pixels.
 pub const WIN_W: usize = 800;
@@ -11,3 +11,12 @@ pub const WIN_B: BBox = BBox::new(0.0, 0.0, WIN_W as _, WIN_H as _);
 
 /// Gravity.
 pub const GRAVITY: f32 = 1.0;
+
+/// Gravity as a vector, pulling down.
+pub const GRAVITY_V: Vec2 = Vec2::new(0.0, GRAVITY);
+
+/// Liquid drag: the fraction of velocity lost every frame inside a liquid.
+pub const DRAG: f32 = 0.08;
+
+/// Gamepad stick deadzone, out of the axis range of `i16::MAX`.
+pub const DEADZONE: f32 = 8000.0;
@@ -5,12 +5,13 @@ use sdl3::{render::Canvas, video::Window};
 pub type EnemyVec = Vec<Box<dyn Entity>>;
 
 /// Updates the enemies.
-pub fn update_enemies(e: &mut EnemyVec, p: &Player, map: TileIter) {
+pub fn update_enemies(e: &mut EnemyVec, p: &mut Player, map: TileIter) {
     // Update enemies.
     e.iter_mut().for_each(|e| e.update(None, map.clone()));
 
-    // Delete enemies that are already dead or are hit by the laser.
-    e.retain(|e| e.is_alive() && !p.get_laser().hits_square(&e.get_body()));
+    // Delete enemies that are already dead or are hit by the player's weapons.
+    e.retain(|e| e.is_alive());
+    p.hit_enemies(e);
 }
 
 /// Draws the enemies.
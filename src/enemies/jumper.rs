@@ -1,45 +1,94 @@
 use crate::{
-    config::GRAVITY,
+    config::{DRAG, GRAVITY_V},
     entity::Entity,
-    geometry::{Square, Vec2},
+    geometry::{Rect, Vec2},
     map::TileIter,
+    tile::Surface,
 };
 use sdl3::{keyboard::KeyboardState, pixels::Color};
 
 /// An enemy that moves side to side while jumping whenever it can.
+#[derive(Clone)]
 pub struct Jumper {
     /// Jumper's body.
-    body: Square,
+    body: Rect,
 
     /// Jumper's velocity.
     v: Vec2,
 
+    /// Walking direction (-1 for left, 1 for right).
+    dir: f32,
+
+    /// The ground physics of the surface it is standing on.
+    surface: Surface,
+
+    /// Whether it is in water or not.
+    submerged: bool,
+
+    /// The local gravity acting on it, before flipping.
+    g: Vec2,
+
+    /// Whether its gravity is flipped or not.
+    flipped: bool,
+
+    /// Health left.
+    hp: u32,
+
     /// Living status boolean.
     is_alive: bool,
 }
 
 impl Jumper {
+    /// Starting health.
+    const HP: u32 = 2;
+
     /// Body side length.
     pub const S: f32 = 24.0;
 
     /// Horizontal speed.
     const VX: f32 = 5.1;
 
+    /// Horizontal accelleration, enough to turn around instantly on regular ground.
+    const ACC: f32 = Self::VX * 2.0;
+
     /// Jumping velocity.
     const JMP_VY: f32 = -5.0;
 
+    /// Jumping velocity when landing.
+    const LAND_VY: f32 = -25.0;
+
+    /// Gravity multiplier while in water; jumpers float up to the surface.
+    const WTR_GRAVITY: f32 = -0.4;
+
     /// Returns a new jumper at the given position.
     pub const fn new(x: f32, y: f32) -> Self {
         Jumper {
-            body: Square::new(x, y, Self::S),
+            body: Rect::square(x, y, Self::S),
             v: Vec2::new(Self::VX, Self::JMP_VY),
+            dir: 1.0,
+            surface: Surface::NONE,
+            submerged: false,
+            g: GRAVITY_V,
+            flipped: false,
+            hp: Self::HP,
             is_alive: true,
         }
     }
+
+    /// Returns the velocity after hitting something along gravity.
+    /// Takes the velocity `v` and gravity `g` along the axis gravity pulls on.
+    /// Jumps if landing, and stops if hitting a ceiling.
+    fn bounce(&self, v: f32, g: f32) -> f32 {
+        if v * g >= 0.0 {
+            Self::LAND_VY * self.surface.jmp * g.signum()
+        } else {
+            0.0
+        }
+    }
 }
 
 impl Entity for Jumper {
-    fn get_body(&self) -> Square {
+    fn get_body(&self) -> Rect {
         self.body
     }
 
@@ -62,6 +111,34 @@ impl Entity for Jumper {
 
     fn set_on_ground(&mut self, _: bool) {}
 
+    fn set_submerged(&mut self, b: bool) {
+        self.submerged = b;
+    }
+
+    fn get_gravity(&self) -> Vec2 {
+        if self.flipped {
+            -self.g
+        } else {
+            self.g
+        }
+    }
+
+    fn set_gravity(&mut self, g: Vec2) {
+        self.g = g;
+    }
+
+    fn set_flipped(&mut self, b: bool) {
+        self.flipped = b;
+    }
+
+    fn get_surface(&self) -> Surface {
+        self.surface
+    }
+
+    fn set_surface(&mut self, s: Surface) {
+        self.surface = s;
+    }
+
     fn set_pos(&mut self, p: Vec2) {
         self.body.x = p.x;
         self.body.y = p.y;
@@ -79,17 +156,58 @@ impl Entity for Jumper {
         self.is_alive = false;
     }
 
+    fn duplicate(&self) -> Option<Box<dyn Entity>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn damage(&mut self, d: u32) {
+        self.hp = self.hp.saturating_sub(d);
+        if self.hp == 0 {
+            self.kill();
+        }
+    }
+
     fn on_col_x(&mut self) {
-        self.v.x = -self.v.x.signum() * Self::VX;
+        // Turn around on walls, unless they are the ground.
+        let g = self.get_gravity();
+        if g.is_x_major() {
+            self.v.x = self.bounce(self.v.x, g.x);
+        } else {
+            self.dir = -self.v.x.signum();
+            self.v.x = 0.0;
+        }
     }
 
     fn on_col_y(&mut self) {
-        self.v.y = if self.v.y >= 0.0 { -25.0 } else { 0.0 }
+        // Turn around on floors and ceilings if they are walls.
+        let g = self.get_gravity();
+        if g.is_x_major() {
+            self.dir = -self.v.y.signum();
+            self.v.y = 0.0;
+        } else {
+            self.v.y = self.bounce(self.v.y, g.y);
+        }
     }
 
     fn update(&mut self, _: Option<&KeyboardState>, map: TileIter) {
-        // Fall.
-        self.v.y += GRAVITY;
+        let g = self.get_gravity();
+
+        // Walk across gravity as fast as the ground allows, keeping any extra speed from launch pads.
+        let vw = if g.is_x_major() {
+            &mut self.v.y
+        } else {
+            &mut self.v.x
+        };
+        if vw.abs() <= Self::VX {
+            *vw = self.surface.accelerate(*vw, self.dir * Self::VX, Self::ACC);
+        }
+
+        // Fall, or float with drag while in water.
+        if self.submerged {
+            self.v = (self.v + g * Self::WTR_GRAVITY) * (1.0 - DRAG);
+        } else {
+            self.v = self.v + g;
+        }
 
         // Handle map collision. Turn around on walls, handle ceilings and floors regularly.
         self.do_map_collision(map);
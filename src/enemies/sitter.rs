@@ -1,39 +1,48 @@
 use crate::{
     entity::Entity,
-    geometry::{Square, Vec2},
+    geometry::{Rect, Vec2},
     map::TileIter,
+    tile::Surface,
 };
 use sdl3::{keyboard::KeyboardState, pixels::Color};
 
 /// An enemy that sits on one place, unaffected by gravity.
+#[derive(Clone)]
 pub struct Sitter {
     /// Sitter's body.
-    body: Square,
+    body: Rect,
+
+    /// Health left.
+    hp: u32,
 
     /// Living status boolean.
     is_alive: bool,
 }
 
 impl Sitter {
+    /// Starting health.
+    const HP: u32 = 3;
+
     /// Body side length.
     pub const S: f32 = 30.0;
 
     /// Returns a new jumper at the given position.
     pub const fn new(x: f32, y: f32) -> Self {
         Sitter {
-            body: Square::new(x, y, Self::S),
+            body: Rect::square(x, y, Self::S),
+            hp: Self::HP,
             is_alive: true,
         }
     }
 }
 
 impl Entity for Sitter {
-    fn get_body(&self) -> Square {
+    fn get_body(&self) -> Rect {
         self.body
     }
 
     fn get_v(&self) -> Vec2 {
-        panic!()
+        Vec2::zero()
     }
 
     fn get_color(&self) -> Color {
@@ -51,23 +60,47 @@ impl Entity for Sitter {
 
     fn set_on_ground(&mut self, _: bool) {}
 
+    fn set_submerged(&mut self, _: bool) {}
+
+    fn get_gravity(&self) -> Vec2 {
+        Vec2::zero()
+    }
+
+    fn set_gravity(&mut self, _: Vec2) {}
+
+    fn set_flipped(&mut self, _: bool) {}
+
+    fn get_surface(&self) -> Surface {
+        Surface::NONE
+    }
+
+    fn set_surface(&mut self, _: Surface) {}
+
     fn set_pos(&mut self, p: Vec2) {
         self.body.x = p.x;
         self.body.y = p.y;
     }
 
-    fn set_vx(&mut self, _: f32) {
-        panic!()
-    }
+    // Sitters can't be moved, even by pads activated by lasers.
+    fn set_vx(&mut self, _: f32) {}
 
-    fn set_vy(&mut self, _: f32) {
-        panic!()
-    }
+    fn set_vy(&mut self, _: f32) {}
 
     fn kill(&mut self) {
         self.is_alive = false;
     }
 
+    fn duplicate(&self) -> Option<Box<dyn Entity>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn damage(&mut self, d: u32) {
+        self.hp = self.hp.saturating_sub(d);
+        if self.hp == 0 {
+            self.kill();
+        }
+    }
+
     fn on_col_x(&mut self) {
         panic!()
     }
@@ -76,5 +109,7 @@ impl Entity for Sitter {
         panic!()
     }
 
+    fn push(&mut self, _: Vec2, _: f32) {}
+
     fn update(&mut self, _: Option<&KeyboardState>, _: TileIter) {}
 }
@@ -1,20 +1,37 @@
 use super::super::{
-    config::GRAVITY,
+    config::{DRAG, GRAVITY_V},
     entity::Entity,
-    geometry::{Square, Vec2},
+    geometry::{Rect, Vec2},
     map::TileIter,
+    tile::Surface,
 };
 use sdl3::{keyboard::KeyboardState, pixels::Color};
 
 /// An enemy that simply walks.
 /// Once it hits a wall, it turns around.
+#[derive(Clone)]
 pub struct Walker {
     /// Body.
-    body: Square,
+    body: Rect,
 
     /// Velocity.
     v: Vec2,
 
+    /// Walking direction (-1 for left, 1 for right).
+    dir: f32,
+
+    /// The ground physics of the surface it is standing on.
+    surface: Surface,
+
+    /// Whether it is in water or not.
+    submerged: bool,
+
+    /// The local gravity acting on it, before flipping.
+    g: Vec2,
+
+    /// Whether its gravity is flipped or not.
+    flipped: bool,
+
     /// Living status boolean.
     is_alive: bool,
 }
@@ -26,18 +43,29 @@ impl Walker {
     /// Horizontal speed.
     const VX: f32 = 7.6;
 
+    /// Horizontal accelleration, enough to turn around instantly on regular ground.
+    const ACC: f32 = Self::VX * 2.0;
+
+    /// Gravity multiplier while in water; walkers slowly sink.
+    const WTR_GRAVITY: f32 = 0.3;
+
     /// Returns a new walker with the given position.
     pub const fn new(x: f32, y: f32) -> Self {
         Self {
-            body: Square::new(x, y, Self::S),
+            body: Rect::square(x, y, Self::S),
             v: Vec2::new(Self::VX, 0.0),
+            dir: 1.0,
+            surface: Surface::NONE,
+            submerged: false,
+            g: GRAVITY_V,
+            flipped: false,
             is_alive: true,
         }
     }
 }
 
 impl Entity for Walker {
-    fn get_body(&self) -> Square {
+    fn get_body(&self) -> Rect {
         self.body
     }
 
@@ -60,6 +88,34 @@ impl Entity for Walker {
 
     fn set_on_ground(&mut self, _: bool) {}
 
+    fn set_submerged(&mut self, b: bool) {
+        self.submerged = b;
+    }
+
+    fn get_gravity(&self) -> Vec2 {
+        if self.flipped {
+            -self.g
+        } else {
+            self.g
+        }
+    }
+
+    fn set_gravity(&mut self, g: Vec2) {
+        self.g = g;
+    }
+
+    fn set_flipped(&mut self, b: bool) {
+        self.flipped = b;
+    }
+
+    fn get_surface(&self) -> Surface {
+        self.surface
+    }
+
+    fn set_surface(&mut self, s: Surface) {
+        self.surface = s;
+    }
+
     fn set_pos(&mut self, p: Vec2) {
         self.body.x = p.x;
         self.body.y = p.y;
@@ -77,17 +133,45 @@ impl Entity for Walker {
         self.is_alive = false;
     }
 
+    fn duplicate(&self) -> Option<Box<dyn Entity>> {
+        Some(Box::new(self.clone()))
+    }
+
     fn on_col_x(&mut self) {
-        self.v.x = -self.v.x.signum() * Self::VX;
+        // Turn around on walls, unless they are the ground.
+        if !self.get_gravity().is_x_major() {
+            self.dir = -self.v.x.signum();
+        }
+        self.v.x = 0.0;
     }
 
     fn on_col_y(&mut self) {
+        // Turn around on floors and ceilings if they are walls.
+        if self.get_gravity().is_x_major() {
+            self.dir = -self.v.y.signum();
+        }
         self.v.y = 0.0;
     }
 
     fn update(&mut self, _: Option<&KeyboardState>, map: TileIter) {
-        // Fall.
-        self.v.y += GRAVITY;
+        let g = self.get_gravity();
+
+        // Walk across gravity as fast as the ground allows, keeping any extra speed from launch pads.
+        let vw = if g.is_x_major() {
+            &mut self.v.y
+        } else {
+            &mut self.v.x
+        };
+        if vw.abs() <= Self::VX {
+            *vw = self.surface.accelerate(*vw, self.dir * Self::VX, Self::ACC);
+        }
+
+        // Fall, or sink with drag while in water.
+        if self.submerged {
+            self.v = (self.v + g * Self::WTR_GRAVITY) * (1.0 - DRAG);
+        } else {
+            self.v = self.v + g;
+        }
 
         // Handle map collision. Turn around on walls, handle ceilings and floors regularly.
         self.do_map_collision(map);
@@ -0,0 +1,30 @@
+/// A level's tuning for the player's energy meter, which weapons like the laser drain to fire.
+#[derive(Clone, Copy, Debug)]
+pub struct Energy {
+    /// The most energy the meter holds.
+    pub max: f32,
+
+    /// Energy regenerated every frame.
+    pub regen: f32,
+
+    /// Energy drained by an uncharged shot; a fully charged shot drains double.
+    pub cost: f32,
+
+    /// Frames it takes to fully charge a shot.
+    pub charge: u32,
+}
+
+impl Energy {
+    /// The tuning used by most levels.
+    pub const DEFAULT: Self = Self::new(100.0, 0.5, 25.0, 60);
+
+    /// Returns a new energy tuning.
+    pub const fn new(max: f32, regen: f32, cost: f32, charge: u32) -> Self {
+        Self {
+            max,
+            regen,
+            cost,
+            charge,
+        }
+    }
+}
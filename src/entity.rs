@@ -1,13 +1,14 @@
 use super::{
-    config::{WIN_B, WIN_H, WIN_W},
-    geometry::{Square, Vec2},
+    config::{GRAVITY_V, WIN_B, WIN_H, WIN_W},
+    geometry::{BBox, Rect, Vec2},
     map::TileIter,
+    tile::{Surface, Wall},
 };
 use sdl3::{keyboard::KeyboardState, pixels::Color, render::Canvas, video::Window};
 
 pub trait Entity {
     /// Returns the entity's body.
-    fn get_body(&self) -> Square;
+    fn get_body(&self) -> Rect;
 
     /// Returns the entity's velocities.
     fn get_v(&self) -> Vec2;
@@ -30,6 +31,17 @@ pub trait Entity {
     /// Kills the entity.
     fn kill(&mut self);
 
+    /// Deals the given damage to the entity, killing it if it runs out of health.
+    fn damage(&mut self, _: u32) {
+        self.kill();
+    }
+
+    /// Tells the entity it touched the checkpoint with the given bounding-box.
+    fn touch_checkpoint(&mut self, _: &BBox) {}
+
+    /// Returns a copy of the entity, or `None` if it can't be copied.
+    fn duplicate(&self) -> Option<Box<dyn Entity>>;
+
     /// Called when the entity collides with something horizontally.
     /// Should handle updating the x-velocity.
     fn on_col_x(&mut self);
@@ -41,6 +53,43 @@ pub trait Entity {
     /// Sets the entity's "on ground" status based on the given boolean.
     fn set_on_ground(&mut self, b: bool);
 
+    /// Tells the entity what wall it is touching, and whether the wall is in the positive (1) or negative (-1)
+    /// walking direction, or `None` if it isn't touching one.
+    fn set_wall(&mut self, _: Option<(Wall, f32)>) {}
+
+    /// Sets whether the entity is inside a liquid based on the given boolean.
+    fn set_submerged(&mut self, b: bool);
+
+    /// Returns the gravity acting on the entity, after flipping.
+    fn get_gravity(&self) -> Vec2;
+
+    /// Sets the local gravity acting on the entity, before flipping.
+    fn set_gravity(&mut self, g: Vec2);
+
+    /// Sets whether the entity's gravity is flipped based on the given boolean.
+    fn set_flipped(&mut self, b: bool);
+
+    /// Returns the ground physics of the surface the entity is standing on.
+    fn get_surface(&self) -> Surface;
+
+    /// Sets the ground physics of the surface the entity is standing on.
+    fn set_surface(&mut self, s: Surface);
+
+    /// Pushes the entity by adding `f` to its velocity, until its speed along `f` reaches `max`.
+    fn push(&mut self, f: Vec2, max: f32) {
+        // A zero force has no direction to push in.
+        if f.len() == 0.0 {
+            return;
+        }
+        let v = self.get_v();
+        let n = f.norm();
+        let add = (max - v.dot(n)).clamp(0.0, f.len());
+        let v = v + n * add;
+
+        self.set_vx(v.x);
+        self.set_vy(v.y);
+    }
+
     /// Updates the entity.
     fn update(&mut self, kbs: Option<&KeyboardState>, map: TileIter);
 
@@ -57,57 +106,151 @@ pub trait Entity {
         Self: Sized,
     {
         let body = self.get_body();
-        let s = body.s;
-        let v = self.get_v();
+        let (w, h) = (body.w, body.h);
+
+        // The entity lands when moving into something along the axis gravity mostly pulls on.
+        let g = self.get_gravity();
+        let side = g.is_x_major();
+
+        // Conveyors carry the entity across gravity without changing its own velocity.
+        let conv = self.get_surface().conv;
+        let v = self.get_v()
+            + if side {
+                Vec2::new(0.0, conv)
+            } else {
+                Vec2::new(conv, 0.0)
+            };
         let mut new_x = body.x + v.x;
         let mut new_y = body.y + v.y;
 
         // Check for out-of-bounds.
-        let (in_x, in_y) = WIN_B.contains_sqr(&Square::new(new_x, new_y, s));
+        let (in_x, in_y) = WIN_B.contains_rect(&Rect::new(new_x, new_y, w, h));
 
         if !in_x {
-            new_x = new_x.clamp(0.0, WIN_W as f32 - s);
+            new_x = new_x.clamp(0.0, WIN_W as f32 - w);
             self.on_col_x();
         }
         if !in_y {
-            new_y = new_y.clamp(0.0, WIN_H as f32 - s);
+            new_y = new_y.clamp(0.0, WIN_H as f32 - h);
             self.on_col_y();
         }
 
         // Set on ground until a landing collision is found.
         // If not done here, walking off an edge will not mark the player as not-grounded.
         self.set_on_ground(false);
+        self.set_wall(None);
+        self.set_surface(Surface::NONE);
+        self.set_submerged(false);
+        self.set_gravity(GRAVITY_V);
+
+        // The height to check horizontal collision at, raised by walking up ramps.
+        let mut walk_y = body.y;
+
+        // Handle ramps first, which are walked up and down instead of being hit from the side.
+        for (bbox, tile) in map.clone().filter(|(_, t)| t.is_solid()) {
+            let Some(k) = tile.get_slope() else {
+                continue;
+            };
+            if new_x >= bbox.a || new_x + w <= bbox.x {
+                continue;
+            }
+
+            // The tall side of the ramp.
+            let wall = if k > 0.0 { bbox.a } else { bbox.x };
+            let was_beside = if k > 0.0 {
+                body.x >= bbox.a
+            } else {
+                body.x + w <= bbox.x
+            };
+
+            // The ramp's top under the entity's uphill edge.
+            let top = tile.top(bbox, if k > 0.0 { new_x + w } else { new_x });
+
+            // Keep ground contact when going downhill, instead of falling off the ramp every frame.
+            let snap = if !side && g.y > 0.0 && v.y >= 0.0 {
+                v.x.abs() * k.abs() + g.y
+            } else {
+                0.0
+            };
+
+            if body.y >= bbox.b && new_y < bbox.b {
+                // Hit from below.
+                new_y = bbox.b;
+                if !side && v.y * g.y > 0.0 {
+                    self.set_on_ground(true);
+                    self.set_surface(tile.get_surface());
+                }
+                self.on_col_y();
+            } else if was_beside && body.y + h > tile.top(bbox, wall) {
+                // Hit the tall side.
+                new_x = if k > 0.0 { bbox.a } else { bbox.x - w };
+                self.on_col_x();
+            } else if new_y < bbox.b && new_y + h + snap > top {
+                // Standing on the ramp.
+                new_y = top - h;
+                walk_y = walk_y.min(new_y);
+                if !side && g.y > 0.0 && v.y >= 0.0 {
+                    self.set_on_ground(true);
+                    self.set_surface(tile.get_surface());
+                }
+                self.on_col_y();
+            } else {
+                continue;
+            }
+
+            tile.do_collision(bbox, self);
+        }
 
-        for (bbox, tile) in map {
+        for (bbox, tile) in map
+            .clone()
+            .filter(|(_, t)| t.is_solid() && t.get_slope().is_none())
+        {
             let mut hit = false;
 
             // Check for horizontal collision.
-            let new_b = Square::new(new_x, body.y, s);
+            let new_b = Rect::new(new_x, walk_y, w, h);
 
-            if bbox.collides_with_sqr(&new_b) {
+            if bbox.collides_with_rect(&new_b) {
                 if v.x > 0.0 {
                     // Hit from left.
-                    new_x = bbox.x - s;
+                    new_x = bbox.x - w;
                 } else {
                     // Hit from right.
                     new_x = bbox.a;
                 }
+                if side && v.x * g.x > 0.0 {
+                    // Landing on a wall with sideways gravity.
+                    self.set_on_ground(true);
+                    self.set_surface(tile.get_surface());
+                } else if !side {
+                    // Touching a wall.
+                    let dir = (bbox.x + bbox.a - new_x * 2.0 - w).signum();
+                    self.set_wall(Some((tile.get_wall(), dir)));
+                }
                 self.on_col_x();
                 hit = true;
             }
 
             // Check for vertical collision.
-            let new_b = Square::new(new_x, new_y, s);
+            let new_b = Rect::new(new_x, new_y, w, h);
 
-            if bbox.collides_with_sqr(&new_b) {
+            if bbox.collides_with_rect(&new_b) {
                 if v.y > 0.0 {
-                    // Landing on ground.
-                    new_y = bbox.y - s;
-                    self.set_on_ground(true);
+                    // Hit from above.
+                    new_y = bbox.y - h;
                 } else {
-                    // Hitting ceiling.
+                    // Hit from below.
                     new_y = bbox.b;
                 }
+                if !side && v.y * g.y > 0.0 {
+                    // Landing on ground, or on a ceiling with reversed gravity.
+                    self.set_on_ground(true);
+                    self.set_surface(tile.get_surface());
+                } else if side {
+                    // Touching a wall with sideways gravity.
+                    let dir = (bbox.y + bbox.b - new_y * 2.0 - h).signum();
+                    self.set_wall(Some((tile.get_wall(), dir)));
+                }
                 self.on_col_y();
                 hit = true;
             }
@@ -118,6 +261,37 @@ pub trait Entity {
             }
         }
 
+        // Run callbacks of non-solid tiles the entity ends up inside of.
+        let new_b = Rect::new(new_x, new_y, w, h);
+
+        for (bbox, tile) in map.filter(|(_, t)| !t.is_solid()) {
+            if bbox.collides_with_rect(&new_b) {
+                tile.do_collision(bbox, self);
+            }
+        }
+
         self.set_pos(Vec2::new(new_x, new_y));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enemies::Walker, tile::TileID};
+
+    /// A steep ramp rising to the right.
+    static RAMP: [(BBox, TileID); 1] = [(BBox::new(100.0, 500.0, 200.0, 600.0), TileID::SlpR)];
+
+    #[test]
+    fn walks_up_ramps() {
+        // Start just above the ramp, walking right.
+        let mut w = Walker::new(110.0, 524.0);
+        w.do_map_collision(RAMP.iter());
+
+        // The walker stands on the ramp's top under its right edge.
+        let b = w.get_body();
+        let (bbox, tile) = RAMP[0];
+        assert_eq!(b.y + b.h, tile.top(&bbox, b.x + b.w));
+        assert!(b.x > 110.0);
+    }
+}
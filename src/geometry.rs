@@ -1,7 +1,7 @@
 mod bbox;
-mod square;
+mod rect;
 mod vec2;
 
 pub use bbox::BBox;
-pub use square::Square;
+pub use rect::Rect;
 pub use vec2::Vec2;
@@ -1,10 +1,10 @@
-use super::{Square, Vec2};
+use super::{Rect, Vec2};
 use sdl3::render::FRect;
 
 /// A 2D bouding-box.
 ///
 /// Note that due to SDL's coordinate system, graphically, `y` is the top and `b` is the bottom.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BBox {
     /// Minimum x.
     pub x: f32,
@@ -27,23 +27,65 @@ impl BBox {
 
     /// Returns the bounding-box's center.
     pub const fn center(&self) -> Vec2 {
-        Vec2::new((self.a - self.x) / 2.0, (self.b - self.y) / 2.0)
+        Vec2::new((self.x + self.a) / 2.0, (self.y + self.b) / 2.0)
     }
 
-    /// Returns true if the bounding-box and square collide, else false.
-    pub const fn collides_with_sqr(&self, other: &Square) -> bool {
-        self.x < other.x + other.s
+    /// Returns true if the point is inside the bounding-box, else false.
+    pub const fn contains(&self, p: Vec2) -> bool {
+        self.x <= p.x && p.x <= self.a && self.y <= p.y && p.y <= self.b
+    }
+
+    /// Casts a ray from `o` in the direction `d` at the bounding-box.
+    /// Returns the distances along the ray at which it enters and exits, or `None` if it misses.
+    /// The entering distance is negative if the ray starts inside.
+    pub fn ray(&self, o: Vec2, d: Vec2) -> Option<(f32, f32)> {
+        let mut near = f32::NEG_INFINITY;
+        let mut far = f32::INFINITY;
+
+        for (o, d, min, max) in [(o.x, d.x, self.x, self.a), (o.y, d.y, self.y, self.b)] {
+            if d == 0.0 {
+                // Parallel to this axis, so it must already be within it.
+                if o < min || o > max {
+                    return None;
+                }
+            } else {
+                let (t0, t1) = ((min - o) / d, (max - o) / d);
+                near = near.max(t0.min(t1));
+                far = far.min(t0.max(t1));
+            }
+        }
+
+        (near <= far).then_some((near, far))
+    }
+
+    /// Returns the outwards normal of the bounding-box's side closest to the point.
+    pub fn normal_at(&self, p: Vec2) -> Vec2 {
+        [
+            ((p.x - self.x).abs(), Vec2::new(-1.0, 0.0)),
+            ((p.x - self.a).abs(), Vec2::new(1.0, 0.0)),
+            ((p.y - self.y).abs(), Vec2::new(0.0, -1.0)),
+            ((p.y - self.b).abs(), Vec2::new(0.0, 1.0)),
+        ]
+        .into_iter()
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .unwrap()
+        .1
+    }
+
+    /// Returns true if the bounding-box and rectangle collide, else false.
+    pub const fn collides_with_rect(&self, other: &Rect) -> bool {
+        self.x < other.x + other.w
             && self.a > other.x
-            && self.y < other.y + other.s
+            && self.y < other.y + other.h
             && self.b > other.y
     }
 
-    /// Returns the containing status of the square in the bounding-box.
+    /// Returns the containing status of the rectangle in the bounding-box.
     /// Returns a tuple for the x and y axes, with true meaning it is contained.
-    pub const fn contains_sqr(&self, other: &Square) -> (bool, bool) {
+    pub const fn contains_rect(&self, other: &Rect) -> (bool, bool) {
         (
-            self.x <= other.x && self.a >= other.x + other.s,
-            self.y <= other.y && self.b >= other.y + other.s,
+            self.x <= other.x && self.a >= other.x + other.w,
+            self.y <= other.y && self.b >= other.y + other.h,
         )
     }
 }
@@ -58,3 +100,14 @@ impl From<&BBox> for Option<FRect> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn center() {
+        let b = BBox::new(300.0, 750.0, 350.0, 800.0);
+        assert_eq!(b.center(), Vec2::new(325.0, 775.0));
+    }
+}
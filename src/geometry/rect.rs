@@ -0,0 +1,54 @@
+use sdl3::render::FRect;
+
+use super::{BBox, Vec2};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rect {
+    /// X-position (left bound).
+    pub x: f32,
+
+    /// Y-position (top bound).
+    pub y: f32,
+
+    /// Width.
+    pub w: f32,
+
+    /// Height.
+    pub h: f32,
+}
+
+impl Rect {
+    /// Returns a new rectangle with the given position, width and height.
+    pub const fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// Returns a new square with the given position and side length.
+    pub const fn square(x: f32, y: f32, s: f32) -> Self {
+        Self::new(x, y, s, s)
+    }
+
+    /// Returns the rectangle's center point.
+    pub const fn center(&self) -> Vec2 {
+        Vec2::new(self.x + self.w / 2.0, self.y + self.h / 2.0)
+    }
+
+    /// Returns the rectangle's bounding-box.
+    pub const fn bbox(&self) -> BBox {
+        BBox::new(self.x, self.y, self.x + self.w, self.y + self.h)
+    }
+
+    /// Returns true if the rectangles collide, else false.
+    pub const fn collides_with(&self, other: &Self) -> bool {
+        self.x <= other.x + other.w
+            && self.x + self.w >= other.x
+            && self.y <= other.y + other.h
+            && self.y + self.h >= other.y
+    }
+}
+
+impl From<&Rect> for Option<FRect> {
+    fn from(value: &Rect) -> Self {
+        Some(FRect::new(value.x, value.y, value.w, value.h))
+    }
+}
@@ -1,7 +1,7 @@
 use sdl3::render::FPoint;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -18,6 +18,32 @@ impl Vec2 {
         Self { x: 0.0, y: 0.0 }
     }
 
+    /// Returns the vector's length.
+    pub fn len(self) -> f32 {
+        self.x.hypot(self.y)
+    }
+
+    /// Returns the vector scaled to a length of one.
+    pub fn norm(self) -> Self {
+        self * (1.0 / self.len())
+    }
+
+    /// Returns the dot product of the vectors.
+    pub fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns true if the vector points more horizontally than vertically, else false.
+    pub fn is_x_major(self) -> bool {
+        self.x.abs() > self.y.abs()
+    }
+
+    /// Returns the vector rotated by the given angle in radians.
+    pub fn rotate(self, a: f32) -> Self {
+        let (sin, cos) = a.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
     /// Returns the direction to the given vector in radians.
     pub fn dir_to(self, other: Self) -> f32 {
         (other.y - self.y).atan2(other.x - self.x)
@@ -71,6 +97,17 @@ impl Mul for Vec2 {
     }
 }
 
+impl Mul<f32> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
 impl Sub for Vec2 {
     type Output = Self;
 
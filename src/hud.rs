@@ -0,0 +1,88 @@
+use super::{
+    config::WIN_W,
+    font::{draw_text, Align, H},
+    map::Map,
+    weapons::WeaponID,
+};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// The information shown on the overlay while playing a level.
+pub struct Hud {
+    /// The index of the map being played.
+    pub map: usize,
+
+    /// The number of enemies left.
+    pub enemies: usize,
+
+    /// Frames since the current attempt started.
+    pub frames: u32,
+
+    /// Attempts since entering the level, including the current one.
+    pub attempts: u32,
+
+    /// The weapon the player is holding.
+    pub weapon: WeaponID,
+
+    /// Tracks whether the held weapon can fire.
+    pub ready: bool,
+}
+
+impl Hud {
+    /// Text scale.
+    const SCALE: f32 = 2.0;
+
+    /// Distance of the text from the window's edges.
+    const MARGIN: f32 = 10.0;
+
+    /// Text color.
+    const COLOR: Color = Color {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 200,
+    };
+
+    /// Color of the weapon's readiness when it can't fire.
+    const WAIT_COLOR: Color = Color {
+        r: 255,
+        g: 100,
+        b: 80,
+        a: 200,
+    };
+
+    /// Draws the overlay.
+    pub fn draw(&self, cnv: &mut Canvas<Window>) {
+        let (x, line) = (WIN_W as f32 - Self::MARGIN, (H + 3) as f32 * Self::SCALE);
+        let lines = [
+            format!("MAP {}/{}", self.map + 1, Map::N),
+            format!("ENEMIES {}", self.enemies),
+            format!("TIME {}", format_time(self.frames)),
+            format!("ATTEMPT {}", self.attempts),
+        ];
+        for (i, s) in lines.iter().enumerate() {
+            let y = Self::MARGIN + i as f32 * line;
+            draw_text(cnv, s, x, y, Self::SCALE, Self::COLOR, Align::Right);
+        }
+
+        // Show the held weapon's readiness under the energy meter.
+        let (s, color) = if self.ready {
+            (format!("{} READY", self.weapon.name()), Self::COLOR)
+        } else {
+            (format!("{} WAIT", self.weapon.name()), Self::WAIT_COLOR)
+        };
+        draw_text(
+            cnv,
+            &s,
+            Self::MARGIN,
+            Self::MARGIN + line,
+            Self::SCALE,
+            color,
+            Align::Left,
+        );
+    }
+}
+
+/// Returns the given number of frames as seconds with hundredths, like `12.34`.
+pub fn format_time(frames: u32) -> String {
+    format!("{}.{:02}", frames / 60, frames % 60 * 100 / 60)
+}
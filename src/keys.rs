@@ -0,0 +1,87 @@
+use super::weapons::WeaponID;
+use sdl3::keyboard::Scancode;
+
+/// The keys bound to the player's actions.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Keys {
+    /// Walks left, or crouches when gravity pulls left.
+    pub left: Scancode,
+
+    /// Walks right, or crouches when gravity pulls right.
+    pub right: Scancode,
+
+    /// Walks up when gravity is sideways, or crouches when gravity pulls up.
+    pub up: Scancode,
+
+    /// Walks down when gravity is sideways, or crouches when gravity pulls down.
+    pub down: Scancode,
+
+    /// Jumps.
+    pub jump: Scancode,
+
+    /// Dashes in the held direction.
+    pub dash: Scancode,
+
+    /// Charges energy-using weapons while held.
+    pub charge: Scancode,
+
+    /// Shoots left.
+    pub aim_left: Scancode,
+
+    /// Shoots right.
+    pub aim_right: Scancode,
+
+    /// Shoots up.
+    pub aim_up: Scancode,
+
+    /// Shoots down.
+    pub aim_down: Scancode,
+
+    /// Restarts the level.
+    pub restart: Scancode,
+
+    /// Switches to each weapon.
+    pub weapons: [Scancode; WeaponID::N],
+}
+
+impl Keys {
+    /// The default key bindings.
+    pub const DEFAULT: Self = Self {
+        left: Scancode::A,
+        right: Scancode::D,
+        up: Scancode::W,
+        down: Scancode::S,
+        jump: Scancode::Space,
+        dash: Scancode::LCtrl,
+        charge: Scancode::LShift,
+        aim_left: Scancode::Left,
+        aim_right: Scancode::Right,
+        aim_up: Scancode::Up,
+        aim_down: Scancode::Down,
+        restart: Scancode::Tab,
+        weapons: WeaponID::KEYS,
+    };
+
+    /// Returns every binding, with the name it is saved under.
+    pub fn bindings(&mut self) -> [(&'static str, &mut Scancode); 12 + WeaponID::N] {
+        let [w1, w2, w3, w4] = &mut self.weapons;
+        [
+            ("left", &mut self.left),
+            ("right", &mut self.right),
+            ("up", &mut self.up),
+            ("down", &mut self.down),
+            ("jump", &mut self.jump),
+            ("dash", &mut self.dash),
+            ("charge", &mut self.charge),
+            ("aim_left", &mut self.aim_left),
+            ("aim_right", &mut self.aim_right),
+            ("aim_up", &mut self.aim_up),
+            ("aim_down", &mut self.aim_down),
+            ("restart", &mut self.restart),
+            ("weapon1", w1),
+            ("weapon2", w2),
+            ("weapon3", w3),
+            ("weapon4", w4),
+        ]
+    }
+}
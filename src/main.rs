@@ -1,17 +1,28 @@
+mod arcade;
+mod checkpoint;
 mod config;
 mod enemies;
+mod energy;
 mod entity;
+mod font;
 mod geometry;
-mod laser;
+mod hud;
+mod keys;
 mod map;
+mod pickup;
 mod player;
+mod run;
+mod save;
+mod scenes;
+mod score;
 mod tile;
+mod weapons;
+mod wind;
 
-use config::{WIN_H, WIN_W};
-use enemies::{draw_enemies, update_enemies};
-use entity::Entity;
-use map::Map;
-use sdl3::{event::Event, pixels::Color, render::BlendMode};
+use config::{DEADZONE, WIN_H, WIN_W};
+use geometry::Vec2;
+use scenes::{Input, Stack, Title};
+use sdl3::{event::Event, gamepad::Axis, pixels::Color, render::BlendMode};
 use std::time::{Duration, Instant};
 
 fn main() {
@@ -25,40 +36,55 @@ fn main() {
         .unwrap();
     let mut cnv = win.into_canvas();
     let mut evp = sdl.event_pump().unwrap();
+    let gss = sdl.gamepad().unwrap();
+    let mut pad = None;
     cnv.set_blend_mode(BlendMode::Blend);
 
     // Framerate limiting (60fps).
     let gap = Duration::from_millis(16);
     let mut prv = Instant::now();
 
-    // Prepare game state.
-    let mut i = 0;
-    let (mut m, mut p, mut e) = Map::init_game(i);
+    // Start at the title screen.
+    let mut stack = Stack::new(Box::new(Title::new()));
 
-    loop {
-        // Stop running if the window was closed.
-        if evp.poll_iter().any(|e| matches!(e, Event::Quit { .. })) {
-            break;
-        }
+    'game: loop {
+        for ev in evp.poll_iter() {
+            match ev {
+                // Stop running if the window was closed.
+                Event::Quit { .. } => break 'game,
 
-        // Update game state.
-        let kbs = evp.keyboard_state();
+                // Use the latest connected gamepad.
+                Event::ControllerDeviceAdded { which, .. } => pad = gss.open(which).ok(),
 
-        p.update(Some(&kbs), m.tile_iter());
-        update_enemies(&mut e, &p, m.tile_iter());
-        p.do_enemy_check(&e);
-        m.update(&kbs, &mut p, &mut e);
+                // Pass new key presses to the current scene.
+                Event::KeyDown {
+                    scancode: Some(key),
+                    repeat: false,
+                    ..
+                } => stack.input(key),
+                _ => {}
+            }
+        }
 
-        // If no enemies left, go to the next map.
-        if e.is_empty() {
-            i = (i + 1) % Map::N;
-            (m, p, e) = Map::init_game(i);
+        // Aim freely while holding the left mouse button, or with a gamepad's right stick.
+        let mos = evp.mouse_state();
+        let inp = Input {
+            kbs: evp.keyboard_state(),
+            mouse: mos.left().then(|| Vec2::new(mos.x(), mos.y())),
+            stick: pad
+                .as_ref()
+                .map(|g| Vec2::new(g.axis(Axis::RightX) as _, g.axis(Axis::RightY) as _))
+                .filter(|v| v.len() > DEADZONE),
+        };
+
+        // Update the current scene, quitting if none are left.
+        stack.update(&inp);
+        if stack.is_empty() {
+            break;
         }
 
-        // Draw game state.
-        m.draw(&mut cnv);
-        p.draw(&mut cnv);
-        draw_enemies(&mut e, &mut cnv);
+        // Draw.
+        stack.draw(&mut cnv);
 
         // Present.
         cnv.present();
@@ -1,15 +1,21 @@
 use super::{
+    checkpoint::{Checkpoint, Respawn},
     config::{WIN_H, WIN_W},
     enemies::EnemyVec,
     enemies::{Jumper, Sitter, Walker},
+    energy::Energy,
     entity::Entity,
     geometry::{BBox, Vec2},
+    keys::Keys,
+    pickup::{Pickup, PickupID, PickupVec},
     player::Player,
     tile::TileID,
+    weapons::WeaponID,
+    wind::Wind,
 };
 use sdl3::{
-    keyboard::{KeyboardState, Scancode},
-    render::Canvas,
+    pixels::Color,
+    render::{Canvas, FRect},
     video::Window,
 };
 use std::slice::Iter;
@@ -23,9 +29,27 @@ pub struct Map {
     /// The map enemies.
     enemies: &'static [fn() -> Box<dyn Entity>],
 
+    /// The map winds.
+    winds: &'static [Wind],
+
+    /// The map pickups.
+    pickups: &'static [Pickup],
+
+    /// The map's tuning for the player's energy meter.
+    energy: Energy,
+
+    /// What happens to enemies killed since the player reached a checkpoint, when respawning at it.
+    respawn: Respawn,
+
     /// The player spawn on the map.
     spawn: Vec2,
 
+    /// The map's name.
+    name: &'static str,
+
+    /// The map's par time in frames, which scores are ranked against.
+    par: u32,
+
     /// The map index.
     i: usize,
 }
@@ -34,40 +58,119 @@ pub struct Map {
 pub type TileIter = Iter<'static, (BBox, TileID)>;
 
 impl Map {
-    /// Returns an initialized game state from the given index.
-    pub fn init_game(i: usize) -> (&'static Self, Player, EnemyVec) {
+    /// Returns an initialized game state from the given index, with the player using the given key bindings.
+    pub fn init_game(i: usize, keys: Keys) -> (&'static Self, Player, EnemyVec, PickupVec) {
         let m = &Self::MAPS[i];
 
         (
             &Self::MAPS[i],
-            Player::new(m.spawn),
+            Player::new(m.spawn, m.energy, keys),
             m.enemies.into_iter().map(|f| f()).collect(),
+            m.pickups.to_vec(),
         )
     }
 
+    /// Returns the map's index.
+    pub fn get_index(&self) -> usize {
+        self.i
+    }
+
+    /// Returns the name of the map with the given index.
+    pub fn get_name(i: usize) -> &'static str {
+        Self::MAPS[i].name
+    }
+
+    /// Returns the par time in frames of the map with the given index.
+    pub fn get_par(i: usize) -> u32 {
+        Self::MAPS[i].par
+    }
+
     /// Returns an iterator of the map's tiles.
     pub fn tile_iter(&self) -> TileIter {
         self.tiles.iter()
     }
 
-    /// Updates the game state; resetting the state if tab was pressed.
-    pub fn update(&self, kbs: &KeyboardState, p: &mut Player, e: &mut EnemyVec) {
-        // If tab is pressed or the player was killed, reset.
-        if kbs.is_scancode_pressed(Scancode::Tab) || !p.is_alive() {
-            (_, *p, *e) = Self::init_game(self.i);
+    /// Updates the game state; resetting the state if `restart` is true, meaning the restart key was just pressed,
+    /// or the player died.
+    /// Resets to the given checkpoint if there is one, else to the start of the map.
+    /// Returns true if the state was reset, else false.
+    pub fn update(
+        &self,
+        restart: bool,
+        c: Option<&Checkpoint>,
+        p: &mut Player,
+        e: &mut EnemyVec,
+        k: &mut PickupVec,
+    ) -> bool {
+        // If restarting or the player was killed, reset.
+        let reset = restart || !p.is_alive();
+        match c {
+            Some(c) if reset => c.respawn(self.respawn, p, e),
+            None if reset => self.reset(p, e, k),
+            _ => {}
+        }
+        reset
+    }
+
+    /// Resets the game state to the map's initial state, keeping the player's key bindings.
+    pub fn reset(&self, p: &mut Player, e: &mut EnemyVec, k: &mut PickupVec) {
+        (_, *p, *e, *k) = Self::init_game(self.i, p.get_keys());
+    }
+
+    /// Pushes the player and enemies with the map's winds at frame `t`.
+    pub fn apply_winds(&self, t: u32, p: &mut Player, e: &mut EnemyVec) {
+        for w in self.winds {
+            w.push(t, p);
+            e.iter_mut().for_each(|e| w.push(t, e.as_mut()));
         }
     }
 
-    /// Draws the map.
+    /// Draws the map's winds at frame `t`.
+    pub fn draw_winds(&self, t: u32, cnv: &mut Canvas<Window>) {
+        self.winds.iter().for_each(|w| w.draw(t, cnv));
+    }
+
+    /// Draws the map's tiles, except for liquids.
     pub fn draw(&self, cnv: &mut Canvas<Window>) {
-        for (b, t) in self.tiles.into_iter() {
+        for (b, t) in self.tiles.iter().filter(|(_, t)| !t.is_liquid()) {
+            cnv.set_draw_color(t.get_color());
+            if t.get_slope().is_some() {
+                // Draw ramps as one pixel wide columns.
+                let cols: Vec<FRect> = (b.x as i32..b.a as i32)
+                    .map(|x| {
+                        let y = t.top(b, x as f32 + 0.5);
+                        FRect::new(x as f32, y, 1.0, b.b - y)
+                    })
+                    .collect();
+                cnv.fill_rects(&cols).unwrap();
+            } else {
+                cnv.fill_rect(b).unwrap();
+            }
+        }
+    }
+
+    /// Draws the map's liquids.
+    /// Should be drawn over entities so that they look submerged.
+    pub fn draw_liquids(&self, cnv: &mut Canvas<Window>) {
+        for (b, t) in self.tiles.iter().filter(|(_, t)| t.is_liquid()) {
             cnv.set_draw_color(t.get_color());
             cnv.fill_rect(b).unwrap();
+
+            // Draw the surface as an opaque line along the top.
+            cnv.set_draw_color(Color {
+                a: 255,
+                ..t.get_color()
+            });
+            cnv.fill_rect(FRect::new(b.x, b.y, b.a - b.x, Self::SURFACE_H))
+                .unwrap();
         }
     }
 
+    /// Height of the surface line drawn on liquids.
+    const SURFACE_H: f32 = 3.0;
+
     /// The number of maps.
-    pub const N: usize = 4;
+    pub const N: usize = 10;
 
     /// Constant initial states for all maps in the game.
     const MAPS: [Map; Self::N] = [
@@ -123,7 +226,17 @@ impl Map {
                     ))
                 },
             ],
+            winds: &[],
+            pickups: &[Pickup::new(
+                WIN_W as f32 / 3.0 + 80.0,
+                WIN_H as f32 - 50.0 - Pickup::S,
+                PickupID::Weapon(WeaponID::Spread),
+            )],
+            energy: Energy::DEFAULT,
+            respawn: Respawn::Restore,
             spawn: Vec2::new((WIN_W as f32) / 3.0, (WIN_H as f32) - 50.0 - Player::S),
+            name: "FIRST STEPS",
+            par: 20 * 60,
             i: 0,
         },
         Map {
@@ -145,6 +258,7 @@ impl Map {
                     BBox::new(50.0, 550.0, WIN_W as f32 - 100.0, 600.0),
                     TileID::Blck,
                 ),
+                (BBox::new(300.0, 500.0, 350.0, 550.0), TileID::Chck),
                 (
                     BBox::new(
                         WIN_W as f32 - Player::S - 120.0,
@@ -177,7 +291,17 @@ impl Map {
                 || Box::new(Walker::new(550.0, 470.0)),
                 || Box::new(Jumper::new(650.0, 480.0)),
             ],
+            winds: &[],
+            pickups: &[
+                Pickup::new(WIN_W as f32 / 2.0, 200.0, PickupID::Pierce),
+                Pickup::new(WIN_W as f32 / 2.0 + 40.0, 200.0, PickupID::Life),
+                Pickup::new(200.0, 550.0 - Pickup::S, PickupID::Weapon(WeaponID::Bolt)),
+            ],
+            energy: Energy::new(150.0, 0.8, 20.0, 45),
+            respawn: Respawn::Keep,
             spawn: Vec2::new(60.0 + Player::S, WIN_H as f32 - Player::S - 50.0),
+            name: "THE HORDE",
+            par: 60 * 60,
             i: 1,
         },
         Map {
@@ -227,7 +351,16 @@ impl Map {
                 || Box::new(Walker::new(250.0, 60.0)),
                 || Box::new(Walker::new(500.0, 75.0)),
             ],
+            winds: &[],
+            pickups: &[
+                Pickup::new(380.0, WIN_H as f32 - 100.0, PickupID::Pierce),
+                Pickup::new(420.0, WIN_H as f32 - 100.0, PickupID::Life),
+            ],
+            energy: Energy::DEFAULT,
+            respawn: Respawn::Restore,
             spawn: Vec2::new(Player::S + 10.0, WIN_H as f32 - Player::S - 50.0),
+            name: "PAD CLIMB",
+            par: 45 * 60,
             i: 2,
         },
         Map {
@@ -286,8 +419,273 @@ impl Map {
                 || Box::new(Sitter::new(355.0, (WIN_H as f32 / 2.0) + 12.5)),
                 || Box::new(Sitter::new(510.0, (WIN_H as f32 / 2.0) + 12.5)),
             ],
+            winds: &[],
+            pickups: &[Pickup::new(
+                200.0,
+                WIN_H as f32 - 50.0 - Pickup::S,
+                PickupID::Weapon(WeaponID::Melee),
+            )],
+            energy: Energy::new(60.0, 0.25, 30.0, 90),
+            respawn: Respawn::Restore,
             spawn: Vec2::new(25.0, WIN_H as f32 - 82.0),
+            name: "FIRE PITS",
+            par: 40 * 60,
             i: 3,
         },
+        Map {
+            tiles: &[
+                (
+                    BBox::new(0.0, WIN_H as f32 - 50.0, 300.0, WIN_H as _),
+                    TileID::Icey,
+                ),
+                (
+                    BBox::new(300.0, WIN_H as f32 - 50.0, 500.0, WIN_H as _),
+                    TileID::CnvR,
+                ),
+                (
+                    BBox::new(500.0, WIN_H as f32 - 50.0, WIN_W as _, WIN_H as _),
+                    TileID::Stky,
+                ),
+                (BBox::new(150.0, 500.0, 650.0, 530.0), TileID::CnvL),
+                (BBox::new(0.0, 300.0, 250.0, 330.0), TileID::Icey),
+                (
+                    BBox::new(WIN_W as f32 - 250.0, 300.0, WIN_W as _, 330.0),
+                    TileID::Stky,
+                ),
+            ],
+            enemies: &[
+                || Box::new(Walker::new(150.0, WIN_H as f32 - Walker::S - 50.0)),
+                || Box::new(Walker::new(600.0, WIN_H as f32 - Walker::S - 50.0)),
+                || Box::new(Jumper::new(400.0, 600.0)),
+                || Box::new(Walker::new(300.0, 500.0 - Walker::S)),
+                || Box::new(Walker::new(50.0, 300.0 - Walker::S)),
+                || Box::new(Sitter::new(400.0, 150.0)),
+            ],
+            winds: &[],
+            pickups: &[],
+            energy: Energy::DEFAULT,
+            respawn: Respawn::Restore,
+            spawn: Vec2::new(60.0, WIN_H as f32 - Player::S - 50.0),
+            name: "SLIPS AND STICKS",
+            par: 45 * 60,
+            i: 4,
+        },
+        Map {
+            tiles: &[
+                (
+                    BBox::new(0.0, WIN_H as f32 - 50.0, WIN_W as _, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (
+                    BBox::new(0.0, 450.0, 200.0, WIN_H as f32 - 50.0),
+                    TileID::Blck,
+                ),
+                (
+                    BBox::new(WIN_W as f32 - 200.0, 450.0, WIN_W as _, WIN_H as f32 - 50.0),
+                    TileID::Blck,
+                ),
+                (
+                    BBox::new(200.0, 450.0, WIN_W as f32 - 200.0, WIN_H as f32 - 50.0),
+                    TileID::Watr,
+                ),
+                (BBox::new(250.0, 280.0, 550.0, 300.0), TileID::Blck),
+                (BBox::new(320.0, 250.0, 480.0, 280.0), TileID::Acid),
+                (BBox::new(680.0, 400.0, 730.0, 450.0), TileID::Chck),
+            ],
+            enemies: &[
+                || Box::new(Walker::new(300.0, 460.0)),
+                || Box::new(Jumper::new(450.0, 300.0)),
+                || Box::new(Walker::new(50.0, 450.0 - Walker::S)),
+                || Box::new(Walker::new(700.0, 450.0 - Walker::S)),
+                || Box::new(Sitter::new(400.0 - (Sitter::S / 2.0), 150.0)),
+                || {
+                    Box::new(Sitter::new(
+                        400.0 - (Sitter::S / 2.0),
+                        WIN_H as f32 - 50.0 - Sitter::S,
+                    ))
+                },
+            ],
+            winds: &[],
+            pickups: &[],
+            energy: Energy::DEFAULT,
+            respawn: Respawn::Restore,
+            spawn: Vec2::new(60.0, 450.0 - Player::S),
+            name: "DEEP WATER",
+            par: 50 * 60,
+            i: 5,
+        },
+        Map {
+            tiles: &[
+                (
+                    BBox::new(0.0, WIN_H as f32 - 50.0, 300.0, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (
+                    BBox::new(300.0, WIN_H as f32 - 50.0, 400.0, WIN_H as _),
+                    TileID::Flip,
+                ),
+                (
+                    BBox::new(400.0, WIN_H as f32 - 50.0, WIN_W as _, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (BBox::new(0.0, 0.0, 500.0, 30.0), TileID::Blck),
+                (BBox::new(500.0, 0.0, 600.0, 30.0), TileID::Flip),
+                (BBox::new(600.0, 0.0, WIN_W as _, 30.0), TileID::Blck),
+                (BBox::new(230.0, 200.0, 250.0, 450.0), TileID::Blck),
+                (
+                    BBox::new(0.0, 300.0, 230.0, WIN_H as f32 - 50.0),
+                    TileID::LowG,
+                ),
+                (BBox::new(250.0, 200.0, 650.0, 300.0), TileID::RgtG),
+                (BBox::new(250.0, 350.0, 650.0, 450.0), TileID::LftG),
+                (BBox::new(650.0, 200.0, WIN_W as _, 600.0), TileID::RevG),
+            ],
+            enemies: &[
+                || Box::new(Walker::new(700.0, 400.0)),
+                || Box::new(Walker::new(600.0, WIN_H as f32 - Walker::S - 50.0)),
+                || Box::new(Walker::new(100.0, 30.0)),
+                || Box::new(Jumper::new(150.0, 600.0)),
+                || Box::new(Jumper::new(400.0, 380.0)),
+                || Box::new(Sitter::new(400.0, 100.0)),
+            ],
+            winds: &[],
+            pickups: &[],
+            energy: Energy::DEFAULT,
+            respawn: Respawn::Restore,
+            spawn: Vec2::new(50.0, WIN_H as f32 - Player::S - 50.0),
+            name: "GRAVITY WELLS",
+            par: 45 * 60,
+            i: 6,
+        },
+        Map {
+            tiles: &[
+                (
+                    BBox::new(0.0, WIN_H as f32 - 50.0, 300.0, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (
+                    BBox::new(300.0, WIN_H as f32 - 30.0, 500.0, WIN_H as _),
+                    TileID::Fire,
+                ),
+                (
+                    BBox::new(500.0, WIN_H as f32 - 50.0, WIN_W as _, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (BBox::new(100.0, 250.0, 300.0, 270.0), TileID::Blck),
+                (BBox::new(500.0, 250.0, 700.0, 270.0), TileID::Blck),
+            ],
+            enemies: &[
+                || Box::new(Walker::new(150.0, 250.0 - Walker::S)),
+                || Box::new(Walker::new(600.0, 250.0 - Walker::S)),
+                || Box::new(Jumper::new(650.0, WIN_H as f32 - Jumper::S - 50.0)),
+                || Box::new(Sitter::new(700.0, 100.0)),
+                || Box::new(Sitter::new(50.0, 100.0)),
+            ],
+            winds: &[
+                Wind::new(
+                    BBox::new(300.0, 150.0, 500.0, WIN_H as f32 - 30.0),
+                    Vec2::new(0.0, -1.6),
+                    9.0,
+                ),
+                Wind::fan(
+                    BBox::new(0.0, 400.0, WIN_W as _, 500.0),
+                    Vec2::new(0.6, 0.0),
+                    6.0,
+                    120,
+                    120,
+                ),
+                Wind::new(
+                    BBox::new(500.0, 0.0, WIN_W as _, 250.0),
+                    Vec2::new(-0.8, 0.0),
+                    7.0,
+                ),
+            ],
+            pickups: &[],
+            energy: Energy::DEFAULT,
+            respawn: Respawn::Restore,
+            spawn: Vec2::new(50.0, WIN_H as f32 - Player::S - 50.0),
+            name: "CROSSWINDS",
+            par: 45 * 60,
+            i: 7,
+        },
+        Map {
+            tiles: &[
+                (
+                    BBox::new(0.0, WIN_H as f32 - 50.0, WIN_W as _, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (BBox::new(150.0, 650.0, 250.0, 750.0), TileID::SlpR),
+                (BBox::new(250.0, 650.0, 450.0, 750.0), TileID::Blck),
+                (BBox::new(450.0, 650.0, 550.0, 750.0), TileID::SlpL),
+                (BBox::new(350.0, 550.0, WIN_W as _, 570.0), TileID::Blck),
+                (BBox::new(550.0, 446.45, WIN_W as _, 550.0), TileID::SlgR),
+                (BBox::new(0.0, 300.0, 200.0, 320.0), TileID::Blck),
+                (BBox::new(200.0, 217.16, 400.0, 300.0), TileID::SlgR),
+                (BBox::new(600.0, 300.0, WIN_W as _, 320.0), TileID::Blck),
+                (BBox::new(600.0, 217.16, WIN_W as _, 300.0), TileID::SlgL),
+            ],
+            enemies: &[
+                || Box::new(Walker::new(50.0, WIN_H as f32 - Walker::S - 50.0)),
+                || Box::new(Walker::new(400.0, 550.0 - Walker::S)),
+                || Box::new(Walker::new(50.0, 300.0 - Walker::S)),
+                || Box::new(Jumper::new(600.0, 300.0)),
+                || Box::new(Sitter::new(100.0, 100.0)),
+                || Box::new(Sitter::new(700.0, 100.0)),
+            ],
+            winds: &[],
+            pickups: &[],
+            energy: Energy::DEFAULT,
+            respawn: Respawn::Restore,
+            spawn: Vec2::new(20.0, WIN_H as f32 - Player::S - 50.0),
+            name: "SLOPES",
+            par: 45 * 60,
+            i: 8,
+        },
+        Map {
+            tiles: &[
+                (
+                    BBox::new(0.0, WIN_H as f32 - 50.0, 600.0, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (
+                    BBox::new(600.0, WIN_H as f32 - 30.0, 700.0, WIN_H as _),
+                    TileID::Fire,
+                ),
+                (
+                    BBox::new(700.0, WIN_H as f32 - 50.0, WIN_W as _, WIN_H as _),
+                    TileID::Blck,
+                ),
+                (
+                    BBox::new(700.0, WIN_H as f32 - 100.0, 750.0, WIN_H as f32 - 50.0),
+                    TileID::MirR,
+                ),
+                (
+                    BBox::new(750.0, WIN_H as f32 - 100.0, WIN_W as _, WIN_H as f32 - 50.0),
+                    TileID::Blck,
+                ),
+                (BBox::new(560.0, 20.0, 580.0, 160.0), TileID::Blck),
+                (BBox::new(580.0, 0.0, WIN_W as _, 20.0), TileID::Refl),
+                (BBox::new(380.0, 350.0, 420.0, 550.0), TileID::Refl),
+                (BBox::new(0.0, 200.0, 100.0, 300.0), TileID::MirL),
+                (
+                    BBox::new(200.0, WIN_H as f32 - 170.0, 212.0, WIN_H as f32 - 50.0),
+                    TileID::Glas,
+                ),
+            ],
+            enemies: &[
+                || Box::new(Sitter::new(700.0, 60.0)),
+                || Box::new(Sitter::new(300.0, 300.0)),
+                || Box::new(Walker::new(300.0, WIN_H as f32 - Walker::S - 50.0)),
+                || Box::new(Jumper::new(450.0, 600.0)),
+            ],
+            winds: &[],
+            pickups: &[],
+            energy: Energy::new(100.0, 0.3, 25.0, 60),
+            respawn: Respawn::Restore,
+            spawn: Vec2::new(50.0, WIN_H as f32 - Player::S - 50.0),
+            name: "MIRRORS",
+            par: 40 * 60,
+            i: 9,
+        },
     ];
 }
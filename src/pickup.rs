@@ -0,0 +1,64 @@
+use super::{geometry::Rect, weapons::WeaponID};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// A vector containing pickups.
+pub type PickupVec = Vec<Pickup>;
+
+/// The kinds of pickups.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PickupID {
+    /// Makes the player's laser pass through every enemy along it.
+    Pierce,
+
+    /// Gives the player an extra life, in arcade mode.
+    Life,
+
+    /// Gives the player the weapon and switches to it.
+    Weapon(WeaponID),
+}
+
+/// An item that is collected when the player touches it.
+#[derive(Clone, Copy)]
+pub struct Pickup {
+    /// The pickup's body.
+    body: Rect,
+
+    /// The kind of pickup.
+    id: PickupID,
+}
+
+impl Pickup {
+    /// Pickup body size (width and height).
+    pub const S: f32 = 16.0;
+
+    /// Returns a new pickup of the given kind at the given position.
+    pub const fn new(x: f32, y: f32, id: PickupID) -> Self {
+        Self {
+            body: Rect::square(x, y, Self::S),
+            id,
+        }
+    }
+
+    /// Returns the pickup's body.
+    pub fn get_body(&self) -> Rect {
+        self.body
+    }
+
+    /// Returns the kind of pickup.
+    pub fn get_id(&self) -> PickupID {
+        self.id
+    }
+
+    /// Draws the pickup.
+    pub fn draw(&self, cnv: &mut Canvas<Window>) {
+        cnv.set_draw_color(match self.id {
+            PickupID::Pierce => Color::RGB(255, 150, 255),
+            PickupID::Life => Color::RGB(255, 80, 120),
+            PickupID::Weapon(WeaponID::Laser) => Color::RGB(255, 255, 255),
+            PickupID::Weapon(WeaponID::Spread) => Color::RGB(255, 200, 50),
+            PickupID::Weapon(WeaponID::Bolt) => Color::RGB(100, 255, 150),
+            PickupID::Weapon(WeaponID::Melee) => Color::RGB(180, 180, 180),
+        });
+        cnv.fill_rect(&self.body).unwrap();
+    }
+}
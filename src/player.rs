@@ -1,25 +1,46 @@
 use super::{
-    config::GRAVITY,
+    config::{DRAG, GRAVITY_V},
     enemies::EnemyVec,
+    energy::Energy,
     entity::Entity,
-    geometry::{Square, Vec2},
-    laser::{Direction, Laser},
+    geometry::{BBox, Rect, Vec2},
+    keys::Keys,
     map::TileIter,
+    pickup::{PickupID, PickupVec},
+    tile::{Surface, Wall},
+    weapons::{Weapon, WeaponID},
 };
 use sdl3::{
-    keyboard::{KeyboardState, Scancode},
+    keyboard::KeyboardState,
     pixels::Color,
-    render::Canvas,
+    render::{Canvas, FRect},
     video::Window,
 };
 
-#[derive(Clone, Copy)]
 pub struct Player {
-    /// The player's laser.
-    laser: Laser,
+    /// The player's weapons, indexed by `WeaponID`, or `None` if not picked up yet.
+    weapons: [Option<Box<dyn Weapon>>; WeaponID::N],
+
+    /// The weapon the player is holding.
+    weapon: WeaponID,
+
+    /// The keys bound to the player's actions.
+    keys: Keys,
+
+    /// The energy left in the player's meter.
+    energy: f32,
+
+    /// The level's tuning for the player's energy meter.
+    tuning: Energy,
+
+    /// The frames the player has held the charge key for, up to the tuning's full charge.
+    charge: u32,
+
+    /// The direction the player is freely aiming in, if any.
+    aim: Option<Vec2>,
 
     /// The player's body.
-    body: Square,
+    body: Rect,
 
     /// The player's velocity.
     v: Vec2,
@@ -27,6 +48,69 @@ pub struct Player {
     /// Tracks whether the player is on the ground or not.
     on_ground: bool,
 
+    /// The ground physics of the surface the player is standing on.
+    surface: Surface,
+
+    /// The wall the player is touching and its direction along walking, if any.
+    wall: Option<(Wall, f32)>,
+
+    /// Tracks whether the player is in water or not.
+    submerged: bool,
+
+    /// The local gravity acting on the player, before flipping.
+    g: Vec2,
+
+    /// Tracks whether the player's gravity is flipped or not.
+    flipped: bool,
+
+    /// Tracks whether jump was held last frame, so each jump and swim stroke needs a new press.
+    prv_jmp: bool,
+
+    /// Frames left in which the player can still jump after leaving the ground.
+    coyote: u32,
+
+    /// Frames left in which a jump press is remembered, to jump as soon as the player lands.
+    buffer: u32,
+
+    /// Tracks whether the player is rising from a jump that can still be cut short.
+    jumping: bool,
+
+    /// Tracks whether the player is crouching, with a shorter body.
+    crouch: bool,
+
+    /// Tracks whether the player is sliding from crouching at speed.
+    slide: bool,
+
+    /// Frames left of the current dash.
+    dash: u32,
+
+    /// The velocity of the current dash.
+    dash_v: Vec2,
+
+    /// Tracks whether the player can still dash in the air before landing.
+    air_dash: bool,
+
+    /// Tracks whether dash was held last frame, so each dash needs a new press.
+    prv_dash: bool,
+
+    /// Frames left in which enemies can't kill the player.
+    invuln: u32,
+
+    /// The player's latest positions, newest first, drawn as a trail while dashing.
+    trail: [Vec2; Self::TRAIL],
+
+    /// The bounding-box of the checkpoint the player last touched, until taken.
+    checkpoint: Option<BBox>,
+
+    /// Enemies the player has killed.
+    kills: u32,
+
+    /// Extra kills made along with another by a single weapon in the same frame.
+    combo: u32,
+
+    /// The bodies of the extra lives the player collected, until taken.
+    lives: Vec<Rect>,
+
     /// Tracks whether the player is dead or not.
     is_alive: bool,
 }
@@ -35,15 +119,66 @@ impl Player {
     /// Player body size (width and height).
     pub const S: f32 = 32.0;
 
+    /// Player body height while crouching.
+    const CROUCH_S: f32 = 18.0;
+
+    /// Max player x-velocity while crouching.
+    const CROUCH_VX: f32 = 3.0;
+
+    /// Speed the player needs to be moving at to slide when crouching.
+    const SLIDE_VX: f32 = 6.0;
+
+    /// Player x-deccelleration while sliding.
+    const SLIDE_DEC: f32 = 0.15;
+
     /// Max player x-velocity.
     const MAX_VX: f32 = 10.0;
 
+    /// Player x-accelleration.
+    const ACC_VX: f32 = 0.5;
+
     /// Player x-deccelleration.
     const DEC_VX: f32 = 0.75;
 
     /// Player jump y-velocity.
     const JMP_VY: f32 = -18.0;
 
+    /// Frames after leaving the ground in which the player can still jump.
+    const COYOTE: u32 = 6;
+
+    /// Frames before landing in which a jump press still jumps.
+    const BUFFER: u32 = 6;
+
+    /// Multiplier of the rising velocity when jump is released early, for short hops.
+    const JMP_CUT: f32 = 0.45;
+
+    /// Max falling speed while sliding down a wall.
+    const WALL_VY: f32 = 3.0;
+
+    /// Walking speed a wall jump kicks the player away from the wall with.
+    const WALL_KICK: f32 = 9.0;
+
+    /// Dash speed.
+    const DASH_V: f32 = 16.0;
+
+    /// Frames a dash lasts for, ignoring gravity.
+    const DASH_T: u32 = 8;
+
+    /// Multiplier of the dash velocity kept when a dash ends.
+    const DASH_END: f32 = 0.4;
+
+    /// Frames the player can't be killed by enemies after starting a dash.
+    const INVULN: u32 = 14;
+
+    /// Number of positions in the dash trail.
+    const TRAIL: usize = 6;
+
+    /// Player swim stroke y-velocity.
+    const SWM_VY: f32 = -7.0;
+
+    /// Gravity multiplier while in water.
+    const WTR_GRAVITY: f32 = 0.25;
+
     /// Player color.
     const COLOR: Color = Color {
         r: 50,
@@ -52,24 +187,214 @@ impl Player {
         a: 255,
     };
 
-    /// Constructs a new player with the given position.
-    pub fn new(p: Vec2) -> Self {
+    /// Color of the player's feet, drawn on the side gravity pulls towards.
+    const FEET_COLOR: Color = Color {
+        r: 25,
+        g: 75,
+        b: 160,
+        a: 255,
+    };
+
+    /// Thickness of the player's feet.
+    const FEET_S: f32 = 6.0;
+
+    /// Energy meter position and size.
+    const METER: FRect = FRect {
+        x: 10.0,
+        y: 10.0,
+        w: 150.0,
+        h: 8.0,
+    };
+
+    /// Energy meter color.
+    const METER_COLOR: Color = Color {
+        r: 80,
+        g: 220,
+        b: 255,
+        a: 255,
+    };
+
+    /// Constructs a new player with the given position, energy tuning and key bindings, with a full energy meter.
+    pub fn new(p: Vec2, tuning: Energy, keys: Keys) -> Self {
         Self {
-            laser: Laser::new_inactive(),
-            body: Square::new(p.x, p.y, Self::S),
+            weapons: [Some(WeaponID::Laser.make()), None, None, None],
+            weapon: WeaponID::Laser,
+            keys,
+            energy: tuning.max,
+            tuning,
+            charge: 0,
+            aim: None,
+            body: Rect::square(p.x, p.y, Self::S),
             v: Vec2::zero(),
             on_ground: false,
+            surface: Surface::NONE,
+            wall: None,
+            submerged: false,
+            g: GRAVITY_V,
+            flipped: false,
+            prv_jmp: false,
+            coyote: 0,
+            buffer: 0,
+            jumping: false,
+            crouch: false,
+            slide: false,
+            dash: 0,
+            dash_v: Vec2::zero(),
+            air_dash: true,
+            prv_dash: false,
+            invuln: 0,
+            trail: [p; Self::TRAIL],
+            checkpoint: None,
+            kills: 0,
+            combo: 0,
+            lives: Vec::new(),
             is_alive: true,
         }
     }
 
-    /// Returns a reference to the player's laser.
-    pub fn get_laser(&self) -> &Laser {
-        &self.laser
+    /// Damages the enemies hit by the player's weapons, then runs the tiles they activated on everyone in reach.
+    pub fn hit_enemies(&mut self, e: &mut EnemyVec) {
+        let mut pads = Vec::new();
+        for w in self.weapons.iter_mut().flatten() {
+            let n = e.len();
+            w.hit_enemies(e);
+            pads.extend(w.take_activated());
+
+            // Killing several enemies at once, like with one laser, makes a combo.
+            let kills = (n - e.len()) as u32;
+            self.kills += kills;
+            self.combo += kills.saturating_sub(1);
+        }
+
+        for (b, t, n) in pads {
+            t.activate(&b, n, self);
+            e.iter_mut().for_each(|e| t.activate(&b, n, e.as_mut()));
+        }
+    }
+
+    /// Returns a new player at the given position with the given kills, keeping this player's weapons and keys.
+    pub fn respawned(&mut self, p: Vec2, (kills, combo): (u32, u32)) -> Self {
+        Self {
+            weapons: std::mem::take(&mut self.weapons),
+            weapon: self.weapon,
+            kills,
+            combo,
+            ..Self::new(p, self.tuning, self.keys)
+        }
+    }
+
+    /// Returns the bounding-box of the checkpoint the player touched since this was last called, if any.
+    pub fn take_checkpoint(&mut self) -> Option<BBox> {
+        self.checkpoint.take()
+    }
+
+    /// Returns the bodies of the extra lives the player collected since this was last called.
+    pub fn take_lives(&mut self) -> Vec<Rect> {
+        std::mem::take(&mut self.lives)
+    }
+
+    /// Returns the number of enemies the player has killed, and how many of those were extra kills in combos.
+    pub fn get_kills(&self) -> (u32, u32) {
+        (self.kills, self.combo)
+    }
+
+    /// Returns the kind of weapon the player is holding.
+    pub fn get_weapon(&self) -> WeaponID {
+        self.weapon
+    }
+
+    /// Returns true if the held weapon can fire and there is enough energy for it, else false.
+    pub fn is_weapon_ready(&self) -> bool {
+        self.weapons[self.weapon as usize]
+            .as_ref()
+            .is_some_and(|w| w.is_ready() && (!w.uses_energy() || self.energy >= self.shot_cost()))
+    }
+
+    /// Returns the keys bound to the player's actions.
+    pub fn get_keys(&self) -> Keys {
+        self.keys
+    }
+
+    /// Sets the direction the player is freely aiming in, or `None` to aim with the arrow keys.
+    pub fn set_aim(&mut self, aim: Option<Vec2>) {
+        self.aim = aim;
+    }
+
+    /// Collects the pickups the player is touching, removing them from the given pickups.
+    pub fn do_pickups(&mut self, items: &mut PickupVec) {
+        items.retain(|i| {
+            if !i.get_body().collides_with(&self.body) {
+                return true;
+            }
+
+            match i.get_id() {
+                PickupID::Pierce => self
+                    .weapons
+                    .iter_mut()
+                    .flatten()
+                    .for_each(|w| w.set_pierce(true)),
+                PickupID::Life => self.lives.push(i.get_body()),
+                PickupID::Weapon(w) => {
+                    self.weapons[w as usize].get_or_insert_with(|| w.make());
+                    self.weapon = w;
+                }
+            }
+            false
+        });
+    }
+
+    /// Draws the player's energy meter, with the current charge shown over the energy it will drain.
+    pub fn draw_meter(&self, cnv: &mut Canvas<Window>) {
+        let m = Self::METER;
+        let fill = |e: f32| m.w * (e / self.tuning.max).clamp(0.0, 1.0);
+
+        cnv.set_draw_color(Color::RGBA(255, 255, 255, 60));
+        cnv.fill_rect(m).unwrap();
+        cnv.set_draw_color(Self::METER_COLOR);
+        cnv.fill_rect(FRect::new(m.x, m.y, fill(self.energy), m.h))
+            .unwrap();
+
+        if self.charge > 0 {
+            let cost = self.shot_cost();
+            cnv.set_draw_color(Color::WHITE);
+            cnv.fill_rect(FRect::new(
+                m.x + fill(self.energy - cost),
+                m.y,
+                fill(cost.min(self.energy)),
+                m.h,
+            ))
+            .unwrap();
+        }
+    }
+
+    /// Returns how far the current shot is charged, from 0 to 1.
+    fn charge_frac(&self) -> f32 {
+        self.charge as f32 / self.tuning.charge.max(1) as f32
+    }
+
+    /// Returns the most frames the current shot can be charged for, which is less than a full charge if the
+    /// energy left can't pay for one.
+    fn max_charge(&self) -> u32 {
+        let Energy { cost, charge, .. } = self.tuning;
+        if cost <= 0.0 {
+            return charge;
+        }
+        let extra = (self.energy / cost - 1.0).max(0.0);
+        ((extra * charge as f32) as u32).min(charge)
+    }
+
+    /// Returns the energy an energy-using shot would drain with the current charge.
+    fn shot_cost(&self) -> f32 {
+        self.tuning.cost * (1.0 + self.charge_frac())
     }
 
     /// Updates the player's living status based on the given enemies.
     pub fn do_enemy_check(&mut self, es: &EnemyVec) {
+        // Enemies can't kill the player while dashing.
+        if self.invuln > 0 {
+            return;
+        }
+
         // If the player collides with an enemies, kill the player.
         for e in es {
             if e.get_body().collides_with(&self.body) {
@@ -79,55 +404,283 @@ impl Player {
         }
     }
 
-    /// Updates the player's velocity based on user input.
-    fn do_movement(&mut self, kbs: &KeyboardState) {
+    /// Handles the user crouching by holding the key towards gravity while grounded.
+    fn do_crouch(&mut self, kbs: &KeyboardState, map: TileIter) {
+        let g = self.get_gravity();
+        let key = if g.is_x_major() {
+            if g.x > 0.0 {
+                self.keys.right
+            } else {
+                self.keys.left
+            }
+        } else if g.y < 0.0 {
+            self.keys.up
+        } else {
+            self.keys.down
+        };
+        self.set_crouch(kbs.is_scancode_pressed(key) && self.on_ground, map);
+    }
+
+    /// Crouches if `want` is true, sliding if moving fast enough, else stands back up if there is room to.
+    fn set_crouch(&mut self, want: bool, map: TileIter) {
+        let g = self.get_gravity();
+        if want && !self.crouch {
+            let vw = if g.is_x_major() { self.v.y } else { self.v.x };
+            self.crouch = true;
+            self.slide = vw.abs() > Self::SLIDE_VX;
+            self.body = self.resized(Self::CROUCH_S);
+        } else if !want && self.crouch {
+            let stand = self.resized(Self::S);
+            if !map
+                .filter(|(_, t)| t.is_solid())
+                .any(|(b, _)| b.collides_with_rect(&stand))
+            {
+                self.body = stand;
+                self.crouch = false;
+                self.slide = false;
+            }
+        }
+    }
+
+    /// Returns the player's body resized to the given height along gravity, keeping its feet in place.
+    fn resized(&self, h: f32) -> Rect {
+        let (b, g) = (self.body, self.get_gravity());
+        if g.is_x_major() {
+            let x = if g.x > 0.0 { b.x + b.w - h } else { b.x };
+            Rect::new(x, b.y, h, Self::S)
+        } else {
+            let y = if g.y < 0.0 { b.y } else { b.y + b.h - h };
+            Rect::new(b.x, y, Self::S, h)
+        }
+    }
+
+    /// Handles the user dashing in the held direction with the dash key.
+    /// Returns true if the player is dashing, overriding normal movement, else false.
+    fn do_dash(&mut self, kbs: &KeyboardState) -> bool {
+        self.trail.rotate_right(1);
+        self.trail[0] = Vec2::new(self.body.x, self.body.y);
+        self.invuln = self.invuln.saturating_sub(1);
+
+        // Landing recharges the air dash.
+        if self.on_ground {
+            self.air_dash = true;
+        }
+
+        let key = |k| kbs.is_scancode_pressed(k) as i32 as f32;
+        let ks = self.keys;
+        let dir = Vec2::new(key(ks.right) - key(ks.left), key(ks.down) - key(ks.up));
+        let press = kbs.is_scancode_pressed(ks.dash) && !self.prv_dash;
+        self.prv_dash = kbs.is_scancode_pressed(ks.dash);
+
+        // Start a dash on the ground, or with the air dash.
+        if press && self.dash == 0 && dir.len() > 0.0 && (self.on_ground || self.air_dash) {
+            self.air_dash &= self.on_ground;
+            self.dash = Self::DASH_T;
+            self.dash_v = dir.norm() * Self::DASH_V;
+            self.invuln = Self::INVULN;
+        }
+
+        if self.dash == 0 {
+            return false;
+        }
+
+        // Dash at a fixed velocity, keeping some of it at the end.
+        self.dash -= 1;
+        self.v = if self.dash == 0 {
+            self.dash_v * Self::DASH_END
+        } else {
+            self.dash_v
+        };
+        self.jumping = false;
+        true
+    }
+
+    /// Tracks the jump key, so the player can jump for a few frames after leaving the ground or after pressing jump.
+    /// Runs while dashing too, where a press is kept until the dash ends and a held key isn't a new press after it.
+    /// Returns true if jump was just pressed, else false.
+    fn track_jump(&mut self, kbs: &KeyboardState) -> bool {
+        let s = kbs.is_scancode_pressed(self.keys.jump);
+        let press = s && !self.prv_jmp;
+        self.prv_jmp = s;
+
+        let climb = self.wall.is_some_and(|(w, _)| w == Wall::Climb);
+        self.coyote = if self.on_ground || climb {
+            Self::COYOTE
+        } else {
+            self.coyote.saturating_sub(1)
+        };
+        self.buffer = if press {
+            Self::BUFFER
+        } else if self.dash > 0 {
+            self.buffer
+        } else {
+            self.buffer.saturating_sub(1)
+        };
+        press
+    }
+
+    /// Updates the player's velocity based on user input, given whether jump was just pressed.
+    fn do_movement(&mut self, kbs: &KeyboardState, press: bool) {
+        // Walking is across gravity; left and right, or up and down when gravity is sideways.
+        let g = self.get_gravity();
+        let side = g.is_x_major();
+        let down = if side { g.x.signum() } else { g.y.signum() };
+
         // Get user movement inputs
-        let a = kbs.is_scancode_pressed(Scancode::A);
-        let d = kbs.is_scancode_pressed(Scancode::D);
-        let s = kbs.is_scancode_pressed(Scancode::Space);
-
-        // Update x-velocity.
-        if a != d {
-            if a && self.v.x > -Self::MAX_VX {
-                self.v.x = (self.v.x - 0.5).max(-Self::MAX_VX);
+        let ks = self.keys;
+        let (a, d) = if side {
+            (
+                kbs.is_scancode_pressed(ks.up),
+                kbs.is_scancode_pressed(ks.down),
+            )
+        } else {
+            (
+                kbs.is_scancode_pressed(ks.left),
+                kbs.is_scancode_pressed(ks.right),
+            )
+        };
+        let s = kbs.is_scancode_pressed(ks.jump);
+
+        // Fall, sinking slowly while in water.
+        if self.submerged {
+            self.v = self.v + g * Self::WTR_GRAVITY;
+        } else {
+            self.v = self.v + g;
+        }
+
+        // Split the velocity into walking and falling parts.
+        let (mut vw, mut vf) = if side {
+            (self.v.y, self.v.x)
+        } else {
+            (self.v.x, self.v.y)
+        };
+
+        // Movement is scaled by the surface being stood on.
+        let sf = self.surface;
+        let max_vx = if self.crouch {
+            Self::CROUCH_VX
+        } else {
+            Self::MAX_VX
+        }
+        .min(sf.cap);
+        let acc_vx = Self::ACC_VX * sf.acc;
+
+        // Update walking velocity, ignoring input while sliding.
+        if self.slide {
+            vw -= f32::min(Self::SLIDE_DEC * sf.dec, vw.abs()) * vw.signum();
+            self.slide = vw.abs() > Self::CROUCH_VX;
+        } else if a != d {
+            if a && vw > -max_vx {
+                vw = (vw - acc_vx).max(-max_vx);
             }
-            if d && self.v.x < Self::MAX_VX {
-                self.v.x = (self.v.x + 0.5).min(Self::MAX_VX);
+            if d && vw < max_vx {
+                vw = (vw + acc_vx).min(max_vx);
             }
         } else {
-            self.v.x -= f32::min(Self::DEC_VX, self.v.x.abs()) * self.v.x.signum();
+            vw -= f32::min(Self::DEC_VX * sf.dec, vw.abs()) * vw.signum();
         }
+        vw = vw.clamp(-sf.cap, sf.cap);
 
-        // Update y-velocity.
-        self.v.y += GRAVITY;
-        if self.on_ground && s {
-            self.v.y = Self::JMP_VY;
+        // Walls the player presses into can be slid down and jumped off of, and climbable walls act like ground.
+        let (wall, dir) = self.wall.unwrap_or((Wall::No, 0.0));
+        let into = (a && dir < 0.0) || (d && dir > 0.0);
+        let slide = wall == Wall::Slide && into && !self.on_ground;
+
+        // Update falling velocity, swimming up on every new press of jump while in water.
+        if self.submerged && press {
+            vf = Self::SWM_VY * down;
+        }
+        if slide && vf * down > Self::WALL_VY {
+            vf = Self::WALL_VY * down;
+        }
+        if self.coyote > 0 && self.buffer > 0 {
+            vf = Self::JMP_VY * sf.jmp * down;
             self.on_ground = false;
+            self.coyote = 0;
+            self.buffer = 0;
+            self.jumping = true;
+        } else if slide && self.buffer > 0 {
+            // Wall jump, kicking away from the wall.
+            vf = Self::JMP_VY * down;
+            vw = -dir * Self::WALL_KICK;
+            self.buffer = 0;
+            self.jumping = true;
+        }
+
+        // Cut the jump short if jump is released while rising.
+        if vf * down >= 0.0 {
+            self.jumping = false;
+        } else if self.jumping && !s {
+            vf *= Self::JMP_CUT;
+            self.jumping = false;
+        }
+
+        self.v = if side {
+            Vec2::new(vf, vw)
+        } else {
+            Vec2::new(vw, vf)
+        };
+        if self.submerged {
+            self.v = self.v * (1.0 - DRAG);
         }
     }
 
-    /// Handles the user shooting.
+    /// Handles the user switching weapons with the number keys.
+    fn do_switch(&mut self, kbs: &KeyboardState) {
+        for (w, k) in WeaponID::ALL.into_iter().zip(self.keys.weapons) {
+            if kbs.is_scancode_pressed(k) && self.weapons[w as usize].is_some() {
+                self.weapon = w;
+            }
+        }
+    }
+
+    /// Handles the user shooting with the held weapon, charging energy-using weapons while the charge key is held.
     fn do_shoot(&mut self, kbs: &KeyboardState, map: TileIter) {
-        // Can't shoot if the laser is already active.
-        if self.laser.is_active() {
+        let uses_energy = self.weapons[self.weapon as usize]
+            .as_ref()
+            .is_some_and(|w| w.uses_energy());
+
+        // Charge while the charge key is held, as far as the energy left can pay for, losing the charge when it's
+        // let go.
+        if uses_energy && kbs.is_scancode_pressed(self.keys.charge) {
+            self.charge = (self.charge + 1).min(self.max_charge());
+        } else {
+            self.charge = 0;
+        }
+        let (c, cost) = if uses_energy {
+            (self.charge_frac(), self.shot_cost())
+        } else {
+            (0.0, 0.0)
+        };
+
+        // Can't shoot until the weapon's cooldown is over, or without enough energy.
+        let Some(w) = &mut self.weapons[self.weapon as usize] else {
+            return;
+        };
+        if !w.is_ready() || self.energy < cost {
             return;
         }
 
-        // Shoot with the first key that is found down.
-        if kbs.is_scancode_pressed(Scancode::Left) {
-            self.laser = Laser::new(self.body.center(), Direction::Left, map);
-        } else if kbs.is_scancode_pressed(Scancode::Right) {
-            self.laser = Laser::new(self.body.center(), Direction::Right, map);
-        } else if kbs.is_scancode_pressed(Scancode::Down) {
-            self.laser = Laser::new(self.body.center(), Direction::Down, map);
-        } else if kbs.is_scancode_pressed(Scancode::Up) {
-            self.laser = Laser::new(self.body.center(), Direction::Up, map);
+        // Shoot where freely aimed, else in any of eight directions with the aim keys.
+        let ks = self.keys;
+        let dir = self.aim.unwrap_or_else(|| {
+            let key = |k| kbs.is_scancode_pressed(k) as i32 as f32;
+            Vec2::new(
+                key(ks.aim_right) - key(ks.aim_left),
+                key(ks.aim_down) - key(ks.aim_up),
+            )
+        });
+
+        if dir.len() > 0.0 {
+            w.fire(self.body.center(), dir, map, c);
+            self.energy -= cost;
+            self.charge = 0;
         }
     }
 }
 
 impl Entity for Player {
-    fn get_body(&self) -> Square {
+    fn get_body(&self) -> Rect {
         self.body
     }
 
@@ -147,6 +700,42 @@ impl Entity for Player {
         self.on_ground = b;
     }
 
+    fn set_wall(&mut self, w: Option<(Wall, f32)>) {
+        self.wall = w;
+    }
+
+    fn touch_checkpoint(&mut self, b: &BBox) {
+        self.checkpoint = Some(*b);
+    }
+
+    fn set_submerged(&mut self, b: bool) {
+        self.submerged = b;
+    }
+
+    fn get_gravity(&self) -> Vec2 {
+        if self.flipped {
+            -self.g
+        } else {
+            self.g
+        }
+    }
+
+    fn set_gravity(&mut self, g: Vec2) {
+        self.g = g;
+    }
+
+    fn set_flipped(&mut self, b: bool) {
+        self.flipped = b;
+    }
+
+    fn get_surface(&self) -> Surface {
+        self.surface
+    }
+
+    fn set_surface(&mut self, s: Surface) {
+        self.surface = s;
+    }
+
     fn set_pos(&mut self, p: Vec2) {
         self.body.x = p.x;
         self.body.y = p.y;
@@ -164,6 +753,10 @@ impl Entity for Player {
         self.is_alive = false;
     }
 
+    fn duplicate(&self) -> Option<Box<dyn Entity>> {
+        None
+    }
+
     fn on_col_x(&mut self) {
         self.v.x = 0.0;
     }
@@ -173,18 +766,83 @@ impl Entity for Player {
     }
 
     fn draw(&self, cnv: &mut Canvas<Window>) {
-        // Draw laser.
-        self.laser.draw(cnv);
+        // Draw weapons.
+        self.weapons.iter().flatten().for_each(|w| w.draw(cnv));
+
+        // Draw the dash trail, fading out with age.
+        if self.invuln > 0 {
+            let a = self.invuln as f32 / Self::INVULN as f32;
+            for (i, p) in self.trail.iter().enumerate().skip(1) {
+                let age = 1.0 - i as f32 / Self::TRAIL as f32;
+                cnv.set_draw_color(Color {
+                    a: (a * age * 120.0) as u8,
+                    ..Self::COLOR
+                });
+                cnv.fill_rect(FRect::new(p.x, p.y, self.body.w, self.body.h))
+                    .unwrap();
+            }
+        }
 
         // Draw player.
         cnv.set_draw_color(self.get_color());
         cnv.fill_rect(&self.body).unwrap();
+
+        // Draw feet on the side gravity pulls towards.
+        let (b, f) = (self.body, Self::FEET_S);
+        let g = self.get_gravity();
+        let feet = if g.is_x_major() {
+            let x = if g.x > 0.0 { b.x + b.w - f } else { b.x };
+            FRect::new(x, b.y, f, b.h)
+        } else {
+            let y = if g.y < 0.0 { b.y } else { b.y + b.h - f };
+            FRect::new(b.x, y, b.w, f)
+        };
+        cnv.set_draw_color(Self::FEET_COLOR);
+        cnv.fill_rect(feet).unwrap();
     }
 
     fn update(&mut self, evp: Option<&KeyboardState>, map: TileIter) {
-        self.laser.update();
-        self.do_movement(evp.unwrap());
+        self.weapons
+            .iter_mut()
+            .flatten()
+            .for_each(|w| w.update(map.clone()));
+        self.energy = (self.energy + self.tuning.regen).min(self.tuning.max);
+        self.do_crouch(evp.unwrap(), map.clone());
+        let press = self.track_jump(evp.unwrap());
+        if !self.do_dash(evp.unwrap()) {
+            self.do_movement(evp.unwrap(), press);
+        }
+        self.do_switch(evp.unwrap());
         self.do_shoot(evp.unwrap(), map.clone());
         self.do_map_collision(map);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tile::TileID;
+
+    #[test]
+    fn stays_crouched_under_ceilings() {
+        let mut p = Player::new(Vec2::new(100.0, 500.0), Energy::DEFAULT, Keys::DEFAULT);
+        p.set_crouch(true, [].iter());
+        assert!(p.crouch);
+
+        // A block right on top of the crouched player, where its head would be standing.
+        let top = p.body.y;
+        static CEILING: [(BBox, TileID); 1] = [(
+            BBox::new(90.0, 400.0, 150.0, 500.0 + Player::S - Player::CROUCH_S),
+            TileID::Blck,
+        )];
+        assert_eq!(CEILING[0].0.b, top);
+
+        p.set_crouch(false, CEILING.iter());
+        assert!(p.crouch);
+        assert_eq!(p.body.h, Player::CROUCH_S);
+
+        p.set_crouch(false, [].iter());
+        assert!(!p.crouch);
+        assert_eq!(p.body.h, Player::S);
+    }
+}
@@ -0,0 +1,314 @@
+use super::{
+    config::WIN_W,
+    entity::Entity,
+    font::{draw_text, Align},
+    geometry::Vec2,
+    hud::format_time,
+    map::Map,
+    player::Player,
+    save::{checksum, data_path, write_atomic, Save},
+};
+use sdl3::{
+    pixels::Color,
+    render::{Canvas, FRect},
+    video::Window,
+};
+use std::fs;
+
+/// The name of the file the fastest speedrun's ghost is saved to, in the user's data directory.
+const FILE: &str = "soderqj.ghost";
+
+/// The first line of a ghost file.
+const HEADER: &str = "soderqj ghost 1";
+
+/// The player's position on every frame of each level of a speedrun, replayed in later runs.
+#[derive(Default, PartialEq, Debug)]
+pub struct Ghost(Vec<Vec<Vec2>>);
+
+impl Ghost {
+    /// Returns the saved ghost, or an empty one if there is no readable ghost.
+    pub fn load() -> Self {
+        fs::read_to_string(data_path(FILE))
+            .ok()
+            .and_then(|s| Self::parse(&s))
+            .unwrap_or_default()
+    }
+
+    /// Writes the ghost to its file.
+    /// The game keeps running if that fails, only losing the ghost.
+    pub fn store(&self) {
+        let _ = write_atomic(&data_path(FILE), &self.text());
+    }
+
+    /// Returns a ghost parsed from the text of its file, or `None` if it is corrupt.
+    ///
+    /// After the header is a line of space separated `x,y` positions for each level,
+    /// and the last line is a checksum of the ones before it.
+    fn parse(s: &str) -> Option<Self> {
+        let (body, sum) = s.trim_end().rsplit_once('\n')?;
+        if sum.strip_prefix("sum ")? != format!("{:016x}", checksum(body)) {
+            return None;
+        }
+
+        // Levels can have no positions, so their lines can be empty.
+        let mut lines = body.split('\n');
+        if lines.next()? != HEADER {
+            return None;
+        }
+        lines
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|p| {
+                        let (x, y) = p.split_once(',')?;
+                        Some(Vec2::new(x.parse().ok()?, y.parse().ok()?))
+                    })
+                    .collect()
+            })
+            .collect::<Option<_>>()
+            .map(Self)
+    }
+
+    /// Returns the ghost as the text of its file.
+    fn text(&self) -> String {
+        let mut s = HEADER.to_string();
+        for l in &self.0 {
+            let ps: Vec<_> = l.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+            s += &format!("\n{}", ps.join(" "));
+        }
+
+        let sum = checksum(&s);
+        s + &format!("\nsum {sum:016x}\n")
+    }
+}
+
+/// A speedrun through every level, timed in simulation frames.
+pub struct Run {
+    /// Frames since the run started.
+    frames: u32,
+
+    /// Frames the run had taken by the end of each completed level.
+    splits: Vec<u32>,
+
+    /// The player's positions so far.
+    ghost: Ghost,
+
+    /// The fastest run's splits, or nothing if there was none.
+    pb: Vec<u32>,
+
+    /// The fewest frames each level took in any run, from before this one.
+    golds: [Option<u32>; Map::N],
+
+    /// The fastest run's positions, drawn as a ghost.
+    pb_ghost: Ghost,
+
+    /// Frames left to show the last split's delta for.
+    show: u32,
+}
+
+impl Run {
+    /// Frames a split's delta is shown for.
+    const SHOW: u32 = 180;
+
+    /// Color of a level done faster than in any run before.
+    const GOLD: Color = Color::RGB(255, 215, 0);
+
+    /// Color of a split ahead of the fastest run.
+    const AHEAD: Color = Color::RGB(25, 255, 100);
+
+    /// Color of a split behind the fastest run.
+    const BEHIND: Color = Color::RGB(255, 60, 60);
+
+    /// Color of the ghost.
+    const GHOST_COLOR: Color = Color {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 70,
+    };
+
+    /// Returns a new run from the first level, racing the fastest run so far.
+    pub fn new() -> Self {
+        let save = Save::load();
+
+        Self {
+            frames: 0,
+            splits: Vec::new(),
+            ghost: Ghost(vec![Vec::new()]),
+            pb: save.get_splits().to_vec(),
+            golds: save.get_golds(),
+            pb_ghost: Ghost::load(),
+            show: 0,
+        }
+    }
+
+    /// Returns the index of the level being run.
+    pub fn get_level(&self) -> usize {
+        self.splits.len()
+    }
+
+    /// Returns the frames the run has taken by the end of each completed level.
+    pub fn get_splits(&self) -> &[u32] {
+        &self.splits
+    }
+
+    /// Returns true if every level was completed, else false.
+    pub fn is_done(&self) -> bool {
+        self.splits.len() == Map::N
+    }
+
+    /// Returns the frames since the level being run started.
+    fn level_frames(&self) -> u32 {
+        self.frames - self.splits.last().copied().unwrap_or(0)
+    }
+
+    /// Advances the run a frame, recording the player's position.
+    pub fn tick(&mut self, p: &Player) {
+        let b = p.get_body();
+        self.frames += 1;
+        self.show = self.show.saturating_sub(1);
+        if let Some(l) = self.ghost.0.last_mut() {
+            l.push(Vec2::new(b.x, b.y));
+        }
+    }
+
+    /// Completes the level being run.
+    pub fn split(&mut self) {
+        self.splits.push(self.frames);
+        self.show = Self::SHOW;
+        if !self.is_done() {
+            self.ghost.0.push(Vec::new());
+        }
+    }
+
+    /// Returns the split of the level with the given index against the fastest run, and the color to show it in.
+    /// Without a fastest run, returns the split itself. Returns `None` if the level wasn't completed.
+    pub fn delta(&self, i: usize) -> Option<(String, Color)> {
+        let t = *self.splits.get(i)?;
+        let seg = t - i.checked_sub(1).map_or(0, |i| self.splits[i]);
+        let pb = self.pb.get(i).copied();
+
+        let color = match (self.golds[i], pb) {
+            (g, _) if g.is_none_or(|g| seg < g) => Self::GOLD,
+            (_, Some(pb)) if t <= pb => Self::AHEAD,
+            _ => Self::BEHIND,
+        };
+        let s = match pb {
+            Some(pb) if t < pb => format!("-{}", format_time(pb - t)),
+            Some(pb) => format!("+{}", format_time(t - pb)),
+            None => format_time(t),
+        };
+        Some((s, color))
+    }
+
+    /// Saves the run, keeping its ghost if it was the fastest.
+    /// Returns true if it was the fastest run, else false.
+    pub fn store(&self) -> bool {
+        let mut save = Save::load();
+        let pb = save.record_run(&self.splits);
+        save.store();
+        if pb {
+            self.ghost.store();
+        }
+        pb
+    }
+
+    /// Draws the fastest run's player where it was at this point of the level.
+    pub fn draw_ghost(&self, cnv: &mut Canvas<Window>) {
+        // The first position is recorded after the level's first frame.
+        let (i, f) = (self.get_level(), self.level_frames());
+        let Some(p) = f
+            .checked_sub(1)
+            .and_then(|f| self.pb_ghost.0.get(i)?.get(f as usize))
+        else {
+            return;
+        };
+        cnv.set_draw_color(Self::GHOST_COLOR);
+        cnv.fill_rect(FRect::new(p.x, p.y, Player::S, Player::S))
+            .unwrap();
+    }
+
+    /// Draws the run's time, and the last split's delta for a while after it.
+    pub fn draw(&self, cnv: &mut Canvas<Window>) {
+        let cx = WIN_W as f32 / 2.0;
+        let time = format_time(self.frames);
+        draw_text(cnv, &time, cx, 10.0, 3.0, Color::WHITE, Align::Center);
+
+        let last = self.get_level().checked_sub(1);
+        if let Some((s, color)) = last.and_then(|i| self.delta(i)).filter(|_| self.show > 0) {
+            draw_text(cnv, &s, cx, 40.0, 2.0, color, Align::Center);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a run racing a fastest run with the given splits and levels' fastest times.
+    fn racing(pb: &[u32], golds: &[u32]) -> Run {
+        let mut g = [None; Map::N];
+        golds.iter().enumerate().for_each(|(i, t)| g[i] = Some(*t));
+        Run {
+            frames: 0,
+            splits: Vec::new(),
+            ghost: Ghost(vec![Vec::new()]),
+            pb: pb.to_vec(),
+            golds: g,
+            pb_ghost: Ghost::default(),
+            show: 0,
+        }
+    }
+
+    /// Completes a level of the run at the given frame.
+    fn split_at(r: &mut Run, frames: u32) {
+        r.frames = frames;
+        r.split();
+    }
+
+    #[test]
+    fn splits_levels() {
+        let mut r = racing(&[], &[]);
+        split_at(&mut r, 100);
+        r.frames = 150;
+
+        assert_eq!(r.get_splits(), [100]);
+        assert_eq!(r.get_level(), 1);
+        assert_eq!(r.level_frames(), 50);
+        assert_eq!(r.ghost.0.len(), 2);
+        assert_eq!(r.show, Run::SHOW);
+    }
+
+    #[test]
+    fn deltas_against_fastest_run() {
+        let mut r = racing(&[100, 250, 400], &[90, 160, 140]);
+        split_at(&mut r, 95);
+        split_at(&mut r, 245);
+        split_at(&mut r, 410);
+
+        // Ahead of the fastest run without a fastest level, then a fastest level, then behind.
+        let ahead = format!("-{}", format_time(5));
+        assert_eq!(r.delta(0), Some((ahead.clone(), Run::AHEAD)));
+        assert_eq!(r.delta(1), Some((ahead, Run::GOLD)));
+        let behind = format!("+{}", format_time(10));
+        assert_eq!(r.delta(2), Some((behind, Run::BEHIND)));
+        assert_eq!(r.delta(3), None);
+    }
+
+    #[test]
+    fn first_run_is_all_gold() {
+        let mut r = racing(&[], &[]);
+        split_at(&mut r, 95);
+
+        assert_eq!(r.delta(0), Some((format_time(95), Run::GOLD)));
+    }
+
+    #[test]
+    fn ghost_round_trips() {
+        let g = Ghost(vec![
+            vec![Vec2::new(1.5, 2.0), Vec2::new(-3.25, 4.0)],
+            Vec::new(),
+        ]);
+
+        assert_eq!(Ghost::parse(&g.text()), Some(g));
+    }
+}
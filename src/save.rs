@@ -0,0 +1,505 @@
+use super::{keys::Keys, map::Map};
+use sdl3::{filesystem::get_pref_path, keyboard::Scancode};
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// The name of the save file, in the user's data directory.
+const FILE: &str = "soderqj.sav";
+
+/// The save file from before saves were versioned, in the working directory.
+const LEGACY: &str = "save.txt";
+
+/// What the save file from before saves were versioned is renamed to once it was migrated.
+const MIGRATED: &str = "save.txt.migrated";
+
+/// The first line of a save file, followed by its version.
+const HEADER: &str = "soderqj save";
+
+/// The version saves are written in.
+/// Version 3 added leaderboards and version 4 added speedruns; older saves are read as having none.
+const VERSION: u32 = 4;
+
+/// The number of scores kept on each level's leaderboard.
+pub const TOP: usize = 10;
+
+/// The best results on a level.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Best {
+    /// The fewest frames the level was completed in.
+    pub time: Option<u32>,
+
+    /// The fewest deaths the level was completed with.
+    pub deaths: Option<u32>,
+}
+
+/// A score on a level's leaderboard.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Entry {
+    /// The name the score was entered under.
+    pub name: String,
+
+    /// The points the score was worth.
+    pub points: u32,
+
+    /// Frames the level was completed in.
+    pub time: u32,
+
+    /// Deaths before the level was completed.
+    pub deaths: u32,
+}
+
+/// The user's options.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Options {
+    /// Tracks whether the overlay is shown while playing.
+    pub hud: bool,
+
+    /// The name last entered on a leaderboard.
+    pub name: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            hud: true,
+            name: String::new(),
+        }
+    }
+}
+
+/// The player's progress through the levels, along with their settings.
+#[derive(PartialEq, Debug)]
+pub struct Save {
+    /// The number of unlocked levels, which are always the first ones.
+    unlocked: usize,
+
+    /// The best results on each level.
+    best: [Best; Map::N],
+
+    /// The leaderboard of each level, from the highest score down.
+    scores: [Vec<Entry>; Map::N],
+
+    /// Frames the fastest full speedrun had taken by the end of each level, if there was one.
+    splits: Vec<u32>,
+
+    /// The fewest frames each level took in any speedrun.
+    golds: [Option<u32>; Map::N],
+
+    /// The key bindings.
+    keys: Keys,
+
+    /// The options.
+    options: Options,
+
+    /// Tracks whether the save file is from a newer version of the game, so it is left untouched.
+    read_only: bool,
+}
+
+impl Default for Save {
+    fn default() -> Self {
+        Self {
+            unlocked: 1,
+            best: [Best::default(); Map::N],
+            scores: Default::default(),
+            splits: Vec::new(),
+            golds: [None; Map::N],
+            keys: Keys::DEFAULT,
+            options: Options::default(),
+            read_only: false,
+        }
+    }
+}
+
+impl Save {
+    /// Returns the saved progress and settings, migrating older saves.
+    /// If there is no save, or it is corrupt, returns the defaults; keeping a corrupt save aside.
+    pub fn load() -> Self {
+        let path = path();
+        let Ok(s) = fs::read_to_string(&path) else {
+            return Self::migrate();
+        };
+
+        Self::read(&s).unwrap_or_else(|| {
+            let _ = fs::rename(&path, path.with_extension("bad"));
+            Self::default()
+        })
+    }
+
+    /// Writes the progress and settings to the save, unless it is from a newer version.
+    /// Returns true if it was written, else false; the game keeps running if it wasn't, only losing the changes.
+    pub fn store(&self) -> bool {
+        !self.read_only && write_atomic(&path(), &self.text()).is_ok()
+    }
+
+    /// Returns the progress from a save older than the current version, storing it in the current one.
+    /// The older save is renamed once it's stored, so it isn't migrated again.
+    /// Returns the defaults if there is no older save.
+    fn migrate() -> Self {
+        let Some(save) = fs::read_to_string(LEGACY)
+            .ok()
+            .and_then(|s| Self::parse_v1(&s))
+        else {
+            return Self::default();
+        };
+        if save.store() {
+            let _ = fs::rename(LEGACY, MIGRATED);
+        }
+        save
+    }
+
+    /// Returns the progress and settings read from the text of a save, or `None` if it is corrupt.
+    /// A save from a newer version can't be read, so the defaults are returned in its place, without ever being
+    /// stored over it.
+    fn read(s: &str) -> Option<Self> {
+        Self::parse(s).or_else(|| {
+            (version(s)? > VERSION).then(|| Self {
+                read_only: true,
+                ..Self::default()
+            })
+        })
+    }
+
+    /// Returns the progress and settings parsed from the text of a save,
+    /// or `None` if it is corrupt or from a newer version.
+    ///
+    /// The first line is the header and version, and the last line is a checksum of the ones before it.
+    /// The lines between hold a record each, starting with its kind; unknown kinds are skipped.
+    fn parse(s: &str) -> Option<Self> {
+        let (body, sum) = s.trim_end().rsplit_once('\n')?;
+        if sum.strip_prefix("sum ")? != format!("{:016x}", checksum(body)) {
+            return None;
+        }
+
+        let mut lines = body.lines();
+        lines.next()?;
+        if !(2..=VERSION).contains(&version(body)?) {
+            return None;
+        }
+
+        let mut save = Self::default();
+        for l in lines {
+            let mut words = l.splitn(3, ' ');
+            match (words.next()?, words.next()?, words.next()) {
+                ("unlocked", n, None) => save.unlocked = Map::N.min(n.parse().ok()?),
+                ("best", i, Some(b)) => {
+                    if let Some(best) = save.best.get_mut(i.parse::<usize>().ok()?) {
+                        *best = parse_best(b)?;
+                    }
+                }
+                // Splits are in order, as each is the time since the start of the run.
+                ("split", i, Some(t)) if i.parse::<usize>() == Ok(save.splits.len()) => {
+                    save.splits.push(t.parse().ok()?);
+                }
+                ("gold", i, Some(t)) => {
+                    if let Some(gold) = save.golds.get_mut(i.parse::<usize>().ok()?) {
+                        *gold = Some(t.parse().ok()?);
+                    }
+                }
+                ("score", i, Some(e)) => {
+                    if let Some(scores) = save.scores.get_mut(i.parse::<usize>().ok()?) {
+                        scores.push(parse_entry(e)?);
+                    }
+                }
+                // Unknown keys keep their default binding.
+                ("key", name, Some(key)) => {
+                    let mut bindings = save.keys.bindings().into_iter();
+                    if let (Some(key), Some((_, k))) =
+                        (Scancode::from_name(key), bindings.find(|(n, _)| *n == name))
+                    {
+                        *k = key;
+                    }
+                }
+                ("option", "hud", Some(v)) => save.options.hud = v == "1",
+                ("option", "name", Some(v)) => save.options.name = v.to_string(),
+                _ => {}
+            }
+        }
+
+        Some(save)
+    }
+
+    /// Returns progress parsed from the text of an unversioned save, or `None` if it is malformed.
+    ///
+    /// The first line is `unlocked N`, followed by a line of `time deaths` for each level.
+    fn parse_v1(s: &str) -> Option<Self> {
+        let mut lines = s.lines();
+        let unlocked = lines.next()?.strip_prefix("unlocked ")?.parse().ok()?;
+        let mut save = Self {
+            unlocked: Map::N.min(unlocked),
+            ..Self::default()
+        };
+
+        // Levels missing from the save are left without results.
+        for (b, l) in save.best.iter_mut().zip(lines) {
+            *b = parse_best(l)?;
+        }
+
+        Some(save)
+    }
+
+    /// Returns the progress and settings as the text of a save.
+    fn text(&self) -> String {
+        let opt = |v: Option<u32>| v.map_or("-".to_string(), |v| v.to_string());
+        let mut s = format!("{HEADER} {VERSION}\nunlocked {}\n", self.unlocked);
+        for (i, b) in self.best.iter().enumerate() {
+            s += &format!("best {i} {} {}\n", opt(b.time), opt(b.deaths));
+        }
+        for (i, scores) in self.scores.iter().enumerate() {
+            for e in scores {
+                s += &format!(
+                    "score {i} {} {} {} {}\n",
+                    e.points, e.time, e.deaths, e.name
+                );
+            }
+        }
+        for (i, t) in self.splits.iter().enumerate() {
+            s += &format!("split {i} {t}\n");
+        }
+        for (i, t) in self.golds.iter().enumerate() {
+            if let Some(t) = t {
+                s += &format!("gold {i} {t}\n");
+            }
+        }
+        let mut keys = self.keys;
+        for (name, k) in keys.bindings() {
+            s += &format!("key {name} {}\n", k.name());
+        }
+        s += &format!("option hud {}\n", self.options.hud as u8);
+        s += &format!("option name {}", self.options.name);
+
+        let sum = checksum(&s);
+        s + &format!("\nsum {sum:016x}\n")
+    }
+
+    /// Returns true if the level with the given index can be played, else false.
+    pub fn is_unlocked(&self, i: usize) -> bool {
+        i < self.unlocked
+    }
+
+    /// Returns the best results on the level with the given index.
+    pub fn get_best(&self, i: usize) -> Best {
+        self.best[i]
+    }
+
+    /// Returns the key bindings.
+    pub fn get_keys(&self) -> Keys {
+        self.keys
+    }
+
+    /// Returns the options.
+    pub fn get_options(&self) -> Options {
+        self.options.clone()
+    }
+
+    /// Sets the options.
+    pub fn set_options(&mut self, options: Options) {
+        self.options = options;
+    }
+
+    /// Records completing the level with the given index, unlocking the next one.
+    /// Returns true if it was completed faster than before, else false.
+    pub fn record(&mut self, i: usize, time: u32, deaths: u32) -> bool {
+        self.unlocked = self.unlocked.max(Map::N.min(i + 2));
+
+        let b = &mut self.best[i];
+        b.deaths = Some(b.deaths.map_or(deaths, |d| d.min(deaths)));
+        let faster = b.time.is_none_or(|t| time < t);
+        if faster {
+            b.time = Some(time);
+        }
+        faster
+    }
+
+    /// Returns the frames the fastest full speedrun had taken by the end of each level, or nothing if there was none.
+    pub fn get_splits(&self) -> &[u32] {
+        &self.splits
+    }
+
+    /// Returns the fewest frames each level took in any speedrun.
+    pub fn get_golds(&self) -> [Option<u32>; Map::N] {
+        self.golds
+    }
+
+    /// Records a full speedrun with the given splits, keeping its fastest levels and the run if it was the fastest.
+    /// Returns true if it was the fastest run, else false.
+    pub fn record_run(&mut self, splits: &[u32]) -> bool {
+        for (i, gold) in self.golds.iter_mut().enumerate() {
+            let t = splits[i] - i.checked_sub(1).map_or(0, |i| splits[i]);
+            *gold = Some(gold.map_or(t, |g| g.min(t)));
+        }
+
+        let faster = self.splits.last().is_none_or(|t| splits[Map::N - 1] < *t);
+        if faster {
+            self.splits = splits.to_vec();
+        }
+        faster
+    }
+
+    /// Returns the leaderboard of the level with the given index, from the highest score down.
+    pub fn get_scores(&self, i: usize) -> &[Entry] {
+        &self.scores[i]
+    }
+
+    /// Returns true if a score worth the given points makes the leaderboard of the level with the given index, else false.
+    pub fn is_top(&self, i: usize, points: u32) -> bool {
+        let s = &self.scores[i];
+        s.len() < TOP || s.iter().any(|e| points > e.points)
+    }
+
+    /// Adds a score to the leaderboard of the level with the given index, below any with as many points.
+    /// Returns its place on the leaderboard, or `None` if it didn't make it.
+    pub fn add_score(&mut self, i: usize, e: Entry) -> Option<usize> {
+        let s = &mut self.scores[i];
+        let n = s.iter().take_while(|o| o.points >= e.points).count();
+        s.insert(n, e);
+        s.truncate(TOP);
+        (n < TOP).then_some(n)
+    }
+}
+
+/// Returns the path of the save file.
+fn path() -> PathBuf {
+    data_path(FILE)
+}
+
+/// Returns the path of the file with the given name, in the user's data directory if there is one.
+pub fn data_path(file: &str) -> PathBuf {
+    get_pref_path("soderqj", "soderqj")
+        .unwrap_or_default()
+        .join(file)
+}
+
+/// Returns the version of a save from the header on its first line, or `None` if there is none.
+fn version(s: &str) -> Option<u32> {
+    s.lines().next()?.strip_prefix(HEADER)?.trim().parse().ok()
+}
+
+/// Returns a level's best results parsed from `time deaths`, where `-` marks a result that was never set.
+fn parse_best(s: &str) -> Option<Best> {
+    let opt = |s: Option<&str>| match s? {
+        "-" => Some(None),
+        s => s.parse().ok().map(Some),
+    };
+    let mut words = s.split_whitespace();
+    Some(Best {
+        time: opt(words.next())?,
+        deaths: opt(words.next())?,
+    })
+}
+
+/// Returns a leaderboard entry parsed from `points time deaths name`.
+fn parse_entry(s: &str) -> Option<Entry> {
+    let mut words = s.splitn(4, ' ');
+    let mut num = || words.next()?.parse().ok();
+    let (points, time, deaths) = (num()?, num()?, num()?);
+    Some(Entry {
+        name: words.next()?.to_string(),
+        points,
+        time,
+        deaths,
+    })
+}
+
+/// Returns the 64-bit FNV-1a hash of the given text, to detect corrupt files.
+pub fn checksum(s: &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Writes the text to a temporary file, then moves it over the given path.
+/// This way the file is never left half written.
+pub fn write_atomic(path: &Path, s: &str) -> io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    let mut f = File::create(&tmp)?;
+    f.write_all(s.as_bytes())?;
+    f.sync_all()?;
+    fs::rename(tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let mut save = Save::default();
+        save.record(0, 300, 2);
+        save.keys.jump = Scancode::W;
+        save.options.hud = false;
+        save.options.name = "ANNA B".to_string();
+        for points in [500, 900, 500] {
+            save.add_score(
+                3,
+                Entry {
+                    name: format!("P{points}"),
+                    points,
+                    time: 600,
+                    deaths: 0,
+                },
+            );
+        }
+        let splits: Vec<u32> = (1..=Map::N as u32).map(|i| i * 600).collect();
+        save.record_run(&splits);
+
+        assert_eq!(Save::parse(&save.text()), Some(save));
+    }
+
+    #[test]
+    fn records_runs() {
+        let mut save = Save::default();
+        let slow: Vec<u32> = (1..=Map::N as u32).map(|i| i * 600).collect();
+        assert!(save.record_run(&slow));
+
+        // A slower run with a faster first level only keeps that level's time.
+        let mut fast_start = slow.iter().map(|t| t + 100).collect::<Vec<_>>();
+        fast_start[0] = 500;
+        assert!(!save.record_run(&fast_start));
+        assert_eq!(save.get_splits(), slow);
+        assert_eq!(save.get_golds()[0], Some(500));
+        assert_eq!(save.get_golds()[1], Some(600));
+
+        let fast: Vec<u32> = slow.iter().map(|t| t - 1).collect();
+        assert!(save.record_run(&fast));
+        assert_eq!(save.get_splits(), fast);
+    }
+
+    #[test]
+    fn keeps_newer_saves() {
+        let mut save = Save::default();
+        save.record(0, 300, 2);
+        let s = save.text().replace(
+            &format!("{HEADER} {VERSION}"),
+            &format!("{HEADER} {}", VERSION + 1),
+        );
+
+        // The newer save can't be read, and the defaults read in its place are never stored over it.
+        let read = Save::read(&s).unwrap();
+        assert!(read.read_only);
+        assert_eq!(read.get_best(0), Best::default());
+        assert!(!read.store());
+        assert_eq!(Save::read("corrupt"), None);
+    }
+
+    #[test]
+    fn detects_corruption() {
+        let mut save = Save::default();
+        save.record(0, 300, 2);
+        let s = save.text().replace("best 0 300", "best 0 100");
+
+        assert_eq!(Save::parse(&s), None);
+    }
+
+    #[test]
+    fn migrates_unversioned() {
+        let save = Save::parse_v1("unlocked 3\n300 2\n- -\n").unwrap();
+
+        assert!(save.is_unlocked(2) && !save.is_unlocked(3));
+        assert_eq!(save.get_best(0).time, Some(300));
+        assert_eq!(save.get_best(1), Best::default());
+        assert_eq!(Save::parse(&save.text()), Some(save));
+    }
+}
@@ -0,0 +1,19 @@
+pub mod campaign;
+pub mod complete;
+pub mod finish;
+pub mod game_over;
+pub mod pause;
+pub mod playing;
+pub mod scene;
+pub mod select;
+pub mod title;
+
+pub use campaign::Campaign;
+pub use complete::Complete;
+pub use finish::Finish;
+pub use game_over::GameOver;
+pub use pause::Pause;
+pub use playing::Playing;
+pub use scene::{Input, Scene, Stack, Trans};
+pub use select::Select;
+pub use title::Title;
@@ -0,0 +1,44 @@
+use super::{Scene, Select, Trans};
+use crate::{
+    config::WIN_W,
+    font::{draw_text, Align},
+    map::Map,
+};
+use sdl3::{keyboard::Scancode, pixels::Color, render::Canvas, video::Window};
+
+/// The screen shown after completing the last level.
+pub struct Campaign;
+
+impl Campaign {
+    /// Returns a new campaign complete screen.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Scene for Campaign {
+    fn input(&mut self, key: Scancode) -> Trans {
+        match key {
+            Scancode::Return | Scancode::Escape => Trans::Pop(1),
+            _ => Trans::None,
+        }
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        draw_text(
+            cnv,
+            "CAMPAIGN COMPLETE!",
+            WIN_W as f32 / 2.0,
+            100.0,
+            6.0,
+            Color::WHITE,
+            Align::Center,
+        );
+
+        // Light up every level.
+        cnv.set_draw_color(Color::RGB(25, 255, 200));
+        for i in 0..Map::N {
+            cnv.fill_rect(Select::level_box(i)).unwrap();
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use super::{Campaign, Input, Playing, Scene, Trans};
+use crate::{
+    config::{WIN_H, WIN_W},
+    font::{draw_text, Align},
+    hud::format_time,
+    map::Map,
+    save::{Entry, Save},
+    score::Score,
+};
+use sdl3::{
+    keyboard::Scancode,
+    pixels::Color,
+    render::{Canvas, FRect},
+    video::Window,
+};
+
+/// The summary shown after completing a level, with its leaderboard.
+pub struct Complete {
+    /// The index of the completed map.
+    i: usize,
+
+    /// The results of completing the level.
+    score: Score,
+
+    /// Tracks whether the level was completed faster than before.
+    best: bool,
+
+    /// The name being entered, if the score made the leaderboard and it hasn't been entered yet.
+    name: Option<String>,
+
+    /// The score's place on the leaderboard, once entered.
+    place: Option<usize>,
+
+    /// The level's leaderboard.
+    scores: Vec<Entry>,
+
+    /// The save the results are recorded in.
+    save: Save,
+
+    /// Tracks whether the save changed since it was last written.
+    unsaved: bool,
+}
+
+impl Complete {
+    /// Max length of a name on the leaderboard.
+    const NAME_LEN: usize = 8;
+
+    /// Color of new bests and high scores.
+    const NEW_COLOR: Color = Color::RGB(25, 255, 200);
+
+    /// Returns a new summary of the map with the given index, recording the results in the given save.
+    /// The save is written on the next update, so the level ending doesn't wait on the disk.
+    pub fn new(i: usize, score: Score, mut save: Save) -> Self {
+        let best = save.record(i, score.time, score.deaths);
+
+        // Start entering a name with the last one entered.
+        let points = score.points(Map::get_par(i));
+        let name = save.is_top(i, points).then(|| save.get_options().name);
+
+        Self {
+            i,
+            score,
+            best,
+            name,
+            place: None,
+            scores: save.get_scores(i).to_vec(),
+            save,
+            unsaved: true,
+        }
+    }
+
+    /// Writes the save if it changed since it was last written.
+    fn flush(&mut self) {
+        if std::mem::take(&mut self.unsaved) {
+            self.save.store();
+        }
+    }
+
+    /// Handles a key press while entering a name.
+    fn input_name(&mut self, key: Scancode) {
+        let Some(name) = &mut self.name else {
+            return;
+        };
+
+        match key {
+            Scancode::Return => self.submit(),
+            Scancode::Escape => self.name = None,
+            Scancode::Backspace => {
+                name.pop();
+            }
+            _ if name.len() >= Self::NAME_LEN => {}
+            Scancode::Space => name.push(' '),
+            _ => {
+                // Only letters and digits have single character names.
+                let s = key.name();
+                if s.len() == 1 && s.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    name.push_str(s);
+                }
+            }
+        }
+    }
+
+    /// Adds the score to the leaderboard under the entered name.
+    fn submit(&mut self) {
+        let Some(name) = self.name.take() else {
+            return;
+        };
+        let name = match name.trim() {
+            "" => "PLAYER".to_string(),
+            s => s.to_string(),
+        };
+
+        let save = &mut self.save;
+        let mut options = save.get_options();
+        options.name = name.clone();
+        save.set_options(options);
+        self.place = save.add_score(
+            self.i,
+            Entry {
+                name,
+                points: self.score.points(Map::get_par(self.i)),
+                time: self.score.time,
+                deaths: self.score.deaths,
+            },
+        );
+        self.scores = save.get_scores(self.i).to_vec();
+        self.unsaved = true;
+    }
+
+    /// Draws the level's leaderboard, from the given height down.
+    fn draw_scores(&self, cnv: &mut Canvas<Window>, y: f32) {
+        let gray = Color::RGB(100, 105, 125);
+        if self.scores.is_empty() {
+            let x = WIN_W as f32 / 2.0;
+            draw_text(cnv, "NO SCORES YET", x, y, 2.0, gray, Align::Center);
+        }
+
+        for (n, e) in self.scores.iter().enumerate() {
+            let y = y + n as f32 * 26.0;
+            let color = if self.place == Some(n) {
+                Self::NEW_COLOR
+            } else {
+                Color::WHITE
+            };
+            let place = format!("{}.", n + 1);
+            draw_text(cnv, &place, 170.0, y, 2.0, gray, Align::Right);
+            draw_text(cnv, &e.name, 190.0, y, 2.0, color, Align::Left);
+            let time = format_time(e.time);
+            draw_text(cnv, &time, 500.0, y, 2.0, gray, Align::Right);
+            let points = e.points.to_string();
+            draw_text(cnv, &points, 630.0, y, 2.0, color, Align::Right);
+        }
+    }
+}
+
+impl Scene for Complete {
+    fn input(&mut self, key: Scancode) -> Trans {
+        if self.name.is_some() {
+            self.input_name(key);
+            return Trans::None;
+        }
+
+        // Don't leave before the results are written.
+        if matches!(key, Scancode::Return | Scancode::Escape) {
+            self.flush();
+        }
+        match key {
+            // Continue to the next level, or finish the campaign after the last one.
+            Scancode::Return if self.i + 1 < Map::N => {
+                Trans::Replace(1, Box::new(Playing::new(self.i + 1)))
+            }
+            Scancode::Return => Trans::Replace(1, Box::new(Campaign::new())),
+            Scancode::Escape => Trans::Pop(1),
+            _ => Trans::None,
+        }
+    }
+
+    fn update(&mut self, _: &Input) -> Trans {
+        self.flush();
+        Trans::None
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        let (cx, s, par) = (WIN_W as f32 / 2.0, self.score, Map::get_par(self.i));
+        draw_text(
+            cnv,
+            &format!("LEVEL {} COMPLETE", self.i + 1),
+            cx,
+            60.0,
+            5.0,
+            Color::WHITE,
+            Align::Center,
+        );
+        draw_text(
+            cnv,
+            &format!("TIME {}  DEATHS {}", format_time(s.time), s.deaths),
+            cx,
+            130.0,
+            3.0,
+            Color::WHITE,
+            Align::Center,
+        );
+        draw_text(
+            cnv,
+            &format!(
+                "KILLS {}  COMBO {}  SCORE {}",
+                s.kills,
+                s.combo,
+                s.points(par)
+            ),
+            cx,
+            170.0,
+            3.0,
+            Color::WHITE,
+            Align::Center,
+        );
+        let rank = s.rank(par);
+        draw_text(
+            cnv,
+            &format!("RANK {}", rank.name()),
+            cx,
+            210.0,
+            4.0,
+            rank.color(),
+            Align::Center,
+        );
+        if self.best {
+            let color = Self::NEW_COLOR;
+            draw_text(cnv, "NEW BEST!", cx, 250.0, 2.0, color, Align::Center);
+        }
+
+        let gray = Color::RGB(100, 105, 125);
+        let (next, back) = match &self.name {
+            Some(name) => {
+                let color = Self::NEW_COLOR;
+                draw_text(
+                    cnv,
+                    "HIGH SCORE! NAME:",
+                    cx,
+                    320.0,
+                    3.0,
+                    color,
+                    Align::Center,
+                );
+                let name = format!("{name}_");
+                draw_text(cnv, &name, cx, 370.0, 4.0, Color::WHITE, Align::Center);
+                ("ENTER: OK", "ESC: SKIP")
+            }
+            None => {
+                self.draw_scores(cnv, 290.0);
+                ("ENTER: NEXT", "ESC: LEVELS")
+            }
+        };
+
+        let y = WIN_H as f32 - 80.0;
+        draw_text(cnv, next, 100.0, y, 3.0, gray, Align::Left);
+        draw_text(cnv, back, WIN_W as f32 - 100.0, y, 3.0, gray, Align::Right);
+
+        // Draw the campaign's progress.
+        let w = WIN_W as f32 - 200.0;
+        let y = WIN_H as f32 - 150.0;
+        cnv.set_draw_color(gray);
+        cnv.fill_rect(FRect::new(100.0, y, w, 20.0)).unwrap();
+        cnv.set_draw_color(Self::NEW_COLOR);
+        cnv.fill_rect(FRect::new(
+            100.0,
+            y,
+            w * (self.i + 1) as f32 / Map::N as f32,
+            20.0,
+        ))
+        .unwrap();
+    }
+}
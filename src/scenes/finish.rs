@@ -0,0 +1,62 @@
+use super::{Scene, Trans};
+use crate::{
+    config::{WIN_H, WIN_W},
+    font::{draw_text, Align},
+    hud::format_time,
+    map::Map,
+    run::Run,
+};
+use sdl3::{keyboard::Scancode, pixels::Color, render::Canvas, video::Window};
+
+/// The splits shown after finishing a speedrun.
+pub struct Finish {
+    /// The finished run.
+    run: Run,
+
+    /// Tracks whether the run was the fastest so far.
+    pb: bool,
+}
+
+impl Finish {
+    /// Returns a new summary of the given run, saving it.
+    pub fn new(run: Run) -> Self {
+        let pb = run.store();
+
+        Self { run, pb }
+    }
+}
+
+impl Scene for Finish {
+    fn input(&mut self, key: Scancode) -> Trans {
+        match key {
+            Scancode::Return | Scancode::Escape => Trans::Pop(1),
+            _ => Trans::None,
+        }
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        let cx = WIN_W as f32 / 2.0;
+        let (title, color) = if self.pb {
+            ("NEW PERSONAL BEST!", Color::RGB(255, 215, 0))
+        } else {
+            ("RUN COMPLETE", Color::WHITE)
+        };
+        draw_text(cnv, title, cx, 80.0, 5.0, color, Align::Center);
+
+        // List each level's split and its delta against the fastest run before this one.
+        let gray = Color::RGB(100, 105, 125);
+        for (i, t) in self.run.get_splits().iter().enumerate() {
+            let y = 180.0 + i as f32 * 40.0;
+            let name = format!("{} {}", i + 1, Map::get_name(i));
+            draw_text(cnv, &name, 80.0, y + 4.0, 2.0, gray, Align::Left);
+            let time = format_time(*t);
+            draw_text(cnv, &time, 560.0, y, 3.0, Color::WHITE, Align::Right);
+            if let Some((s, color)) = self.run.delta(i) {
+                draw_text(cnv, &s, 720.0, y, 3.0, color, Align::Right);
+            }
+        }
+
+        let y = WIN_H as f32 - 100.0;
+        draw_text(cnv, "PRESS ENTER", cx, y, 3.0, gray, Align::Center);
+    }
+}
@@ -0,0 +1,53 @@
+use super::{Scene, Trans};
+use crate::{
+    arcade::Arcade,
+    config::{WIN_H, WIN_W},
+    font::{draw_text, Align},
+    map::Map,
+};
+use sdl3::{keyboard::Scancode, pixels::Color, render::Canvas, video::Window};
+
+/// The screen shown when an arcade game ends, by running out of lives or completing every level.
+pub struct GameOver {
+    /// The ended game.
+    arcade: Arcade,
+}
+
+impl GameOver {
+    /// Returns a new screen for the given ended game.
+    pub fn new(arcade: Arcade) -> Self {
+        Self { arcade }
+    }
+}
+
+impl Scene for GameOver {
+    fn input(&mut self, key: Scancode) -> Trans {
+        // Go back to the title screen.
+        match key {
+            Scancode::Return | Scancode::Escape => Trans::Pop(1),
+            _ => Trans::None,
+        }
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        let cx = WIN_W as f32 / 2.0;
+        let (title, color, level) = if self.arcade.is_done() {
+            (
+                "ALL CLEAR!",
+                Color::RGB(255, 215, 0),
+                "EVERY LEVEL".to_string(),
+            )
+        } else {
+            let i = self.arcade.get_level();
+            let level = format!("LEVEL {} {}", i + 1, Map::get_name(i));
+            ("GAME OVER", Color::RGB(255, 60, 60), level)
+        };
+        draw_text(cnv, title, cx, 150.0, 8.0, color, Align::Center);
+        draw_text(cnv, &level, cx, 300.0, 3.0, Color::WHITE, Align::Center);
+        let points = format!("SCORE {}", self.arcade.get_points());
+        draw_text(cnv, &points, cx, 350.0, 4.0, Color::WHITE, Align::Center);
+
+        let (y, gray) = (WIN_H as f32 - 100.0, Color::RGB(100, 105, 125));
+        draw_text(cnv, "PRESS ENTER", cx, y, 3.0, gray, Align::Center);
+    }
+}
@@ -0,0 +1,116 @@
+use super::{Input, Scene, Trans};
+use crate::{
+    config::{WIN_H, WIN_W},
+    font::{draw_text, Align},
+};
+use sdl3::{keyboard::Scancode, pixels::Color, render::Canvas, video::Window};
+
+/// An overlay that freezes the level below it, with a menu to leave it.
+pub struct Pause {
+    /// The index of the selected option.
+    sel: usize,
+
+    /// Tracks whether the level below should be stepped a frame.
+    step: bool,
+
+    /// Tracks whether the level is part of the campaign, which is left back to the level select menu.
+    /// Other modes are left back to the title screen.
+    campaign: bool,
+}
+
+impl Pause {
+    /// The number of options in the menu.
+    const N: usize = 4;
+
+    /// The key that steps the level a frame, in debug builds.
+    const STEP_KEY: Scancode = Scancode::Period;
+
+    /// Returns a new pause overlay, over a level that is part of the campaign or not.
+    pub fn new(campaign: bool) -> Self {
+        Self {
+            sel: 0,
+            step: false,
+            campaign,
+        }
+    }
+
+    /// Returns the menu's options.
+    fn options(&self) -> [&'static str; Self::N] {
+        let leave = if self.campaign { "LEVELS" } else { "TITLE" };
+        ["RESUME", "RESTART", leave, "QUIT"]
+    }
+}
+
+impl Scene for Pause {
+    fn input(&mut self, key: Scancode) -> Trans {
+        let (sel, n) = (self.sel, Self::N);
+        match key {
+            Scancode::Up => self.sel = (sel + n - 1) % n,
+            Scancode::Down => self.sel = (sel + 1) % n,
+            Self::STEP_KEY if cfg!(debug_assertions) => self.step = true,
+            _ => {}
+        }
+
+        match key {
+            Scancode::Escape => Trans::Pop(1),
+            Scancode::Return => match self.sel {
+                0 => Trans::Pop(1),
+                1 => Trans::Restart,
+                // Leave the level too, back to the level select menu or the title screen.
+                2 => Trans::Pop(2),
+                _ => Trans::Quit,
+            },
+            _ => Trans::None,
+        }
+    }
+
+    fn update(&mut self, _: &Input) -> Trans {
+        if std::mem::take(&mut self.step) {
+            Trans::Step
+        } else {
+            Trans::None
+        }
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        // Dim the frozen level.
+        cnv.set_draw_color(Color::RGBA(0, 0, 0, 150));
+        cnv.fill_rect(None).unwrap();
+
+        let (x, y) = (WIN_W as f32 / 2.0, WIN_H as f32 / 2.0);
+        draw_text(
+            cnv,
+            "PAUSED",
+            x,
+            y - 150.0,
+            6.0,
+            Color::WHITE,
+            Align::Center,
+        );
+        for (i, s) in self.options().iter().enumerate() {
+            let color = if i == self.sel {
+                Color::WHITE
+            } else {
+                Color::RGB(100, 105, 125)
+            };
+            let y = y - 40.0 + i as f32 * 50.0;
+            draw_text(cnv, s, x, y, 4.0, color, Align::Center);
+        }
+
+        if cfg!(debug_assertions) {
+            draw_text(
+                cnv,
+                "PERIOD: STEP FRAME",
+                x,
+                WIN_H as f32 - 60.0,
+                2.0,
+                Color::RGB(100, 105, 125),
+                Align::Center,
+            );
+        }
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
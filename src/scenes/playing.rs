@@ -0,0 +1,317 @@
+use super::{Complete, Finish, GameOver, Input, Pause, Scene, Trans};
+use crate::{
+    arcade::Arcade,
+    checkpoint::Checkpoint,
+    enemies::{draw_enemies, update_enemies, EnemyVec},
+    entity::Entity,
+    hud::Hud,
+    map::Map,
+    pickup::{PickupID, PickupVec},
+    player::Player,
+    run::Run,
+    save::Save,
+    score::Score,
+};
+use sdl3::{keyboard::Scancode, render::Canvas, video::Window};
+
+/// A level being played.
+pub struct Playing {
+    /// The map being played.
+    m: &'static Map,
+
+    /// The player.
+    p: Player,
+
+    /// The enemies left.
+    e: EnemyVec,
+
+    /// The pickups left.
+    k: PickupVec,
+
+    /// Frame counter, for timed parts of maps.
+    t: u32,
+
+    /// Frames since the current attempt started.
+    frames: u32,
+
+    /// Attempts since entering the level, including the current one.
+    attempts: u32,
+
+    /// Times the player died since entering the level.
+    deaths: u32,
+
+    /// Tracks whether the restart key was pressed since the last update.
+    restart: bool,
+
+    /// Tracks whether the overlay is shown or not.
+    hud: bool,
+
+    /// The speedrun the level is part of, if any.
+    run: Option<Run>,
+
+    /// The arcade game the level is part of, if any.
+    arcade: Option<Arcade>,
+
+    /// The checkpoint the player respawns at, if one was reached.
+    cp: Option<Checkpoint>,
+
+    /// The save, loaded when the level started.
+    save: Save,
+}
+
+impl Playing {
+    /// Returns a new scene playing the map with the given index.
+    pub fn new(i: usize) -> Self {
+        Self::with_mode(i, None, None, Save::load())
+    }
+
+    /// Returns a new scene playing the next level of the given speedrun.
+    pub fn speedrun(run: Run) -> Self {
+        Self::with_mode(run.get_level(), Some(run), None, Save::load())
+    }
+
+    /// Returns a new scene playing the next level of the given arcade game.
+    pub fn arcade(arcade: Arcade) -> Self {
+        Self::with_mode(arcade.get_level(), None, Some(arcade), Save::load())
+    }
+
+    /// Returns a new scene playing the map with the given index, as part of the given speedrun or arcade game, with
+    /// the given save.
+    fn with_mode(i: usize, run: Option<Run>, arcade: Option<Arcade>, save: Save) -> Self {
+        let (m, p, e, k) = Map::init_game(i, save.get_keys());
+
+        let mut s = Self {
+            m,
+            p,
+            e,
+            k,
+            t: 0,
+            frames: 0,
+            attempts: 1,
+            deaths: 0,
+            restart: false,
+            hud: save.get_options().hud,
+            run,
+            arcade,
+            cp: None,
+            save,
+        };
+        s.remove_lives();
+        s
+    }
+
+    /// Removes the extra life pickups that can't be collected: all of them outside arcade mode, and the ones
+    /// already collected in it.
+    fn remove_lives(&mut self) {
+        let a = self.arcade.as_ref();
+        self.k.retain(|k| {
+            k.get_id() != PickupID::Life || a.is_some_and(|a| !a.is_collected(&k.get_body()))
+        });
+    }
+
+    /// Starts a new attempt at the level, after it was reset.
+    /// The attempt's time keeps going after respawning at a checkpoint.
+    /// In arcade mode, every new attempt costs a life.
+    fn new_attempt(&mut self) {
+        self.remove_lives();
+        if let Some(a) = &mut self.arcade {
+            a.lose_life();
+        }
+        if self.cp.is_none() {
+            self.frames = 0;
+        }
+        self.attempts += 1;
+    }
+
+    /// Resets the level if the restart key was pressed or the player died, starting a new attempt.
+    /// Only dying counts as a death, not restarting.
+    fn do_reset(&mut self) {
+        let restart = std::mem::take(&mut self.restart);
+        let died = !self.p.is_alive();
+        let (m, p, e, k) = (self.m, &mut self.p, &mut self.e, &mut self.k);
+        if m.update(restart, self.cp.as_ref(), p, e, k) {
+            self.deaths += died as u32;
+            self.new_attempt();
+        }
+    }
+}
+
+impl Scene for Playing {
+    fn input(&mut self, key: Scancode) -> Trans {
+        match key {
+            // Restart once per press, however long the key is held.
+            _ if key == self.p.get_keys().restart => {
+                self.restart = true;
+                Trans::None
+            }
+            Scancode::Escape => {
+                let campaign = self.run.is_none() && self.arcade.is_none();
+                Trans::Push(Box::new(Pause::new(campaign)))
+            }
+            Scancode::H => {
+                self.hud = !self.hud;
+                let mut options = self.save.get_options();
+                options.hud = self.hud;
+                self.save.set_options(options);
+                self.save.store();
+                Trans::None
+            }
+            _ => Trans::None,
+        }
+    }
+
+    fn update(&mut self, inp: &Input) -> Trans {
+        let (m, p, e, k) = (self.m, &mut self.p, &mut self.e, &mut self.k);
+
+        // Aim freely while holding the left mouse button, or with a gamepad's right stick.
+        let aim = inp.mouse.map(|v| v - p.get_body().center()).or(inp.stick);
+        p.set_aim(aim);
+
+        // Update game state.
+        m.apply_winds(self.t, p, e);
+        p.update(Some(&inp.kbs), m.tile_iter());
+        update_enemies(e, p, m.tile_iter());
+        p.do_pickups(k);
+        p.do_enemy_check(e);
+        self.t = self.t.wrapping_add(1);
+        self.frames += 1;
+
+        // Reaching a new checkpoint makes it the respawn point.
+        if let Some(b) = p.take_checkpoint() {
+            if !self.cp.as_ref().is_some_and(|c| c.is_at(&b)) {
+                self.cp = Some(Checkpoint::new(b, p, e));
+            }
+        }
+
+        // Collect extra lives before a reset can take them from the player.
+        let lives = p.take_lives();
+        if let Some(a) = &mut self.arcade {
+            lives.into_iter().for_each(|b| a.collect_life(b));
+        }
+
+        // Start a new attempt if the level was reset.
+        self.do_reset();
+        if let Some(r) = &mut self.run {
+            r.tick(&self.p);
+        }
+
+        // The arcade game ends when it runs out of lives.
+        if let Some(a) = self.arcade.take() {
+            if a.is_over() {
+                return Trans::Replace(1, Box::new(GameOver::new(a)));
+            }
+            self.arcade = Some(a);
+        }
+
+        // If no enemies left, the level is complete.
+        if self.e.is_empty() {
+            // Go straight to the next level of a speedrun, until all are done.
+            if let Some(mut run) = self.run.take() {
+                run.split();
+                return if run.is_done() {
+                    Trans::Replace(1, Box::new(Finish::new(run)))
+                } else {
+                    Trans::Replace(1, Box::new(Self::speedrun(run)))
+                };
+            }
+
+            let (kills, combo) = self.p.get_kills();
+            let score = Score {
+                time: self.frames,
+                kills,
+                combo,
+                deaths: self.deaths,
+            };
+
+            // Go straight to the next level of an arcade game too, scoring the level.
+            if let Some(mut a) = self.arcade.take() {
+                a.complete(score.points(Map::get_par(m.get_index())));
+                return if a.is_done() {
+                    Trans::Replace(1, Box::new(GameOver::new(a)))
+                } else {
+                    Trans::Replace(1, Box::new(Self::arcade(a)))
+                };
+            }
+            let save = std::mem::take(&mut self.save);
+            Trans::Replace(1, Box::new(Complete::new(m.get_index(), score, save)))
+        } else {
+            Trans::None
+        }
+    }
+
+    fn restart(&mut self) {
+        self.cp = None;
+        self.m.reset(&mut self.p, &mut self.e, &mut self.k);
+        self.new_attempt();
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        let (m, p, k) = (self.m, &self.p, &self.k);
+
+        m.draw(cnv);
+        if let Some(c) = &self.cp {
+            c.draw(cnv);
+        }
+        m.draw_winds(self.t, cnv);
+        k.iter().for_each(|k| k.draw(cnv));
+        if let Some(r) = &self.run {
+            r.draw_ghost(cnv);
+        }
+        p.draw(cnv);
+        draw_enemies(&mut self.e, cnv);
+        m.draw_liquids(cnv);
+        p.draw_meter(cnv);
+
+        if self.hud {
+            Hud {
+                map: m.get_index(),
+                enemies: self.e.len(),
+                frames: self.frames,
+                attempts: self.attempts,
+                weapon: p.get_weapon(),
+                ready: p.is_weapon_ready(),
+            }
+            .draw(cnv);
+        }
+        if let Some(r) = &self.run {
+            r.draw(cnv);
+        }
+        if let Some(a) = &self.arcade {
+            a.draw(cnv);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pickup::Pickup;
+
+    #[test]
+    fn held_restart_costs_one_life() {
+        let mut s = Playing::with_mode(0, None, Some(Arcade::new()), Save::default());
+        let lives = s.arcade.as_ref().unwrap().get_lives();
+
+        // Holding the key only sends a single press, while the level keeps updating.
+        s.input(s.p.get_keys().restart);
+        (0..5).for_each(|_| s.do_reset());
+
+        assert_eq!(s.arcade.as_ref().unwrap().get_lives(), lives - 1);
+        assert_eq!(s.attempts, 2);
+        assert_eq!(s.deaths, 0);
+    }
+
+    #[test]
+    fn collected_lives_stay_collected() {
+        // THE HORDE has an extra life pickup.
+        let mut s = Playing::with_mode(1, None, Some(Arcade::new()), Save::default());
+        let is_life = |k: &Pickup| k.get_id() == PickupID::Life;
+        let life = *s.k.iter().find(|k| is_life(k)).unwrap();
+
+        s.arcade.as_mut().unwrap().collect_life(life.get_body());
+        s.input(s.p.get_keys().restart);
+        s.do_reset();
+
+        assert!(!s.k.iter().any(is_life));
+    }
+}
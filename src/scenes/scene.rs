@@ -0,0 +1,161 @@
+use crate::geometry::Vec2;
+use sdl3::{keyboard::KeyboardState, keyboard::Scancode, render::Canvas, video::Window};
+
+/// The user input a scene is updated with.
+pub struct Input<'a> {
+    /// The keyboard state.
+    pub kbs: KeyboardState<'a>,
+
+    /// The mouse position, if the left mouse button is held.
+    pub mouse: Option<Vec2>,
+
+    /// The gamepad's right stick, if pushed past the deadzone.
+    pub stick: Option<Vec2>,
+}
+
+/// A change to the scene stack, returned by scenes.
+pub enum Trans {
+    /// Stay in the current scene.
+    None,
+
+    /// Push a scene on top of the current one.
+    Push(Box<dyn Scene>),
+
+    /// Pop the given number of scenes.
+    Pop(usize),
+
+    /// Pop the given number of scenes, then push a scene.
+    Replace(usize, Box<dyn Scene>),
+
+    /// Pop the top scene and restart the one below it.
+    Restart,
+
+    /// Update the scene below the top one for a single frame.
+    Step,
+
+    /// Quit the game.
+    Quit,
+}
+
+/// A screen of the game, like a menu or a level being played.
+pub trait Scene {
+    /// Handles a new key press, returning the transition it causes.
+    fn input(&mut self, _: Scancode) -> Trans {
+        Trans::None
+    }
+
+    /// Updates the scene, returning the transition it causes.
+    fn update(&mut self, _: &Input) -> Trans {
+        Trans::None
+    }
+
+    /// Restarts the scene, for scenes that can be restarted.
+    fn restart(&mut self) {}
+
+    /// Called when the scene is back on top after the scenes above it were popped.
+    fn resume(&mut self) {}
+
+    /// Draws the scene.
+    fn draw(&mut self, cnv: &mut Canvas<Window>);
+
+    /// Returns true if the scene is drawn over the scene below it, else false.
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
+
+/// A stack of scenes, where only the top scene gets input and updates.
+pub struct Stack {
+    /// The scenes, from bottom to top.
+    scenes: Vec<Box<dyn Scene>>,
+}
+
+impl Stack {
+    /// Returns a new stack with the given scene.
+    pub fn new(s: Box<dyn Scene>) -> Self {
+        Self { scenes: vec![s] }
+    }
+
+    /// Returns true if there are no scenes left, meaning the game should quit, else false.
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    /// Passes a new key press to the top scene.
+    pub fn input(&mut self, key: Scancode) {
+        if let Some(s) = self.scenes.last_mut() {
+            let t = s.input(key);
+            self.apply(t);
+        }
+    }
+
+    /// Updates the top scene.
+    pub fn update(&mut self, inp: &Input) {
+        if let Some(s) = self.scenes.last_mut() {
+            match s.update(inp) {
+                Trans::Step => self.step(inp),
+                t => self.apply(t),
+            }
+        }
+    }
+
+    /// Updates the scene below the top one for a single frame.
+    /// If that changes the stack, the top scene is popped first.
+    fn step(&mut self, inp: &Input) {
+        let n = self.scenes.len();
+        if n < 2 {
+            return;
+        }
+
+        match self.scenes[n - 2].update(inp) {
+            Trans::None => {}
+            t => {
+                self.pop(1);
+                self.apply(t);
+            }
+        }
+    }
+
+    /// Draws the top scene, over the scenes below it if it is an overlay.
+    pub fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        let beg = self
+            .scenes
+            .iter()
+            .rposition(|s| !s.is_overlay())
+            .unwrap_or(0);
+        self.scenes[beg..].iter_mut().for_each(|s| s.draw(cnv));
+    }
+
+    /// Applies the given transition.
+    fn apply(&mut self, t: Trans) {
+        match t {
+            Trans::None => {}
+            Trans::Push(s) => self.scenes.push(s),
+            Trans::Pop(n) => {
+                self.pop(n);
+                if let Some(s) = self.scenes.last_mut() {
+                    s.resume();
+                }
+            }
+            Trans::Replace(n, s) => {
+                self.pop(n);
+                self.scenes.push(s);
+            }
+            Trans::Restart => {
+                self.pop(1);
+                if let Some(s) = self.scenes.last_mut() {
+                    s.restart();
+                }
+            }
+            // Steps need the input, so they are handled when updating.
+            Trans::Step => {}
+            Trans::Quit => self.scenes.clear(),
+        }
+    }
+
+    /// Pops the given number of scenes.
+    fn pop(&mut self, n: usize) {
+        let n = self.scenes.len().saturating_sub(n);
+        self.scenes.truncate(n);
+    }
+}
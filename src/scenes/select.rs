@@ -0,0 +1,130 @@
+use super::{Playing, Scene, Trans};
+use crate::{
+    config::WIN_W,
+    font::{draw_text, Align, H},
+    hud::format_time,
+    map::Map,
+    save::Save,
+};
+use sdl3::{
+    keyboard::Scancode,
+    pixels::Color,
+    render::{Canvas, FRect},
+    video::Window,
+};
+
+/// The level select menu.
+pub struct Select {
+    /// The index of the selected map.
+    i: usize,
+
+    /// The saved progress, for which levels are unlocked and their best results.
+    save: Save,
+}
+
+impl Select {
+    /// Height of each level's row, including the gap below it.
+    const ROW_H: f32 = 52.0;
+
+    /// Top of the first level's row.
+    const TOP: f32 = 200.0;
+
+    /// Horizontal margin of the rows.
+    const MARGIN: f32 = 40.0;
+
+    /// Returns a new level select menu, with the map with the given index selected.
+    pub fn new(i: usize) -> Self {
+        Self {
+            i,
+            save: Save::load(),
+        }
+    }
+
+    /// Returns the row of the map with the given index.
+    pub fn level_box(i: usize) -> FRect {
+        FRect::new(
+            Self::MARGIN,
+            Self::TOP + i as f32 * Self::ROW_H,
+            WIN_W as f32 - Self::MARGIN * 2.0,
+            Self::ROW_H - 10.0,
+        )
+    }
+}
+
+impl Scene for Select {
+    fn input(&mut self, key: Scancode) -> Trans {
+        let (i, n) = (self.i, Map::N);
+        self.i = match key {
+            Scancode::Up => (i + n - 1) % n,
+            Scancode::Down => (i + 1) % n,
+            _ => i,
+        };
+
+        match key {
+            Scancode::Return if self.save.is_unlocked(self.i) => {
+                Trans::Push(Box::new(Playing::new(self.i)))
+            }
+            Scancode::Escape => Trans::Pop(1),
+            _ => Trans::None,
+        }
+    }
+
+    fn resume(&mut self) {
+        // Levels may have been completed since the menu was opened.
+        self.save = Save::load();
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        draw_text(
+            cnv,
+            "SELECT LEVEL",
+            WIN_W as f32 / 2.0,
+            60.0,
+            6.0,
+            Color::WHITE,
+            Align::Center,
+        );
+
+        // Label the columns.
+        let gray = Color::RGB(100, 105, 125);
+        let (left, time_x, deaths_x) = (Self::MARGIN + 20.0, 590.0, WIN_W as f32 - 60.0);
+        let y = Self::TOP - 30.0;
+        draw_text(cnv, "LEVEL", left, y, 2.0, gray, Align::Left);
+        draw_text(cnv, "BEST", time_x, y, 2.0, gray, Align::Right);
+        draw_text(cnv, "DEATHS", deaths_x, y, 2.0, gray, Align::Right);
+
+        // List every level with its best results, or as locked.
+        let scale = 3.0;
+        for i in 0..Map::N {
+            let b = Self::level_box(i);
+            let unlocked = self.save.is_unlocked(i);
+            if i == self.i {
+                cnv.set_draw_color(if unlocked {
+                    Color::RGB(60, 63, 80)
+                } else {
+                    Color::RGB(80, 35, 35)
+                });
+                cnv.fill_rect(b).unwrap();
+            }
+
+            let color = if unlocked {
+                Color::WHITE
+            } else {
+                Color::RGB(70, 72, 85)
+            };
+            let y = b.y + (b.h - H as f32 * scale) / 2.0;
+            let name = format!("{} {}", i + 1, Map::get_name(i));
+            draw_text(cnv, &name, left, y, scale, color, Align::Left);
+
+            if unlocked {
+                let best = self.save.get_best(i);
+                let time = best.time.map_or("-".to_string(), format_time);
+                draw_text(cnv, &time, time_x, y, scale, color, Align::Right);
+                let deaths = best.deaths.map_or("-".to_string(), |d| d.to_string());
+                draw_text(cnv, &deaths, deaths_x, y, scale, color, Align::Right);
+            } else {
+                draw_text(cnv, "LOCKED", deaths_x, y, scale, color, Align::Right);
+            }
+        }
+    }
+}
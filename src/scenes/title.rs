@@ -0,0 +1,74 @@
+use super::{Playing, Scene, Select, Trans};
+use crate::{
+    arcade::Arcade,
+    config::{WIN_H, WIN_W},
+    font::{draw_text, Align},
+    geometry::Vec2,
+    run::Run,
+};
+use sdl3::{
+    keyboard::Scancode,
+    pixels::Color,
+    render::{Canvas, FRect},
+    video::Window,
+};
+
+/// The title screen, shown when the game starts, with a menu of the game's modes.
+pub struct Title {
+    /// The index of the selected mode.
+    sel: usize,
+}
+
+impl Title {
+    /// The menu's options.
+    const OPTIONS: [&'static str; 3] = ["CAMPAIGN", "SPEEDRUN", "ARCADE"];
+
+    /// Returns a new title screen.
+    pub fn new() -> Self {
+        Self { sel: 0 }
+    }
+}
+
+impl Scene for Title {
+    fn input(&mut self, key: Scancode) -> Trans {
+        let (sel, n) = (self.sel, Self::OPTIONS.len());
+        match key {
+            Scancode::Up => self.sel = (sel + n - 1) % n,
+            Scancode::Down => self.sel = (sel + 1) % n,
+            _ => {}
+        }
+
+        match key {
+            Scancode::Return => match self.sel {
+                0 => Trans::Push(Box::new(Select::new(0))),
+                1 => Trans::Push(Box::new(Playing::speedrun(Run::new()))),
+                _ => Trans::Push(Box::new(Playing::arcade(Arcade::new()))),
+            },
+            Scancode::Escape => Trans::Quit,
+            _ => Trans::None,
+        }
+    }
+
+    fn draw(&mut self, cnv: &mut Canvas<Window>) {
+        // Draw the player shooting a laser across the screen.
+        let (x, y) = (WIN_W as f32 / 4.0, WIN_H as f32 / 2.0);
+        cnv.set_draw_color(Color::RGB(50, 150, 255));
+        cnv.fill_rect(FRect::new(x - 16.0, y - 16.0, 32.0, 32.0))
+            .unwrap();
+        cnv.set_draw_color(Color::WHITE);
+        cnv.draw_line(Vec2::new(x, y), Vec2::new(WIN_W as f32, y))
+            .unwrap();
+
+        let cx = WIN_W as f32 / 2.0;
+        draw_text(cnv, "SODERQJ", cx, 150.0, 12.0, Color::WHITE, Align::Center);
+        for (i, s) in Self::OPTIONS.iter().enumerate() {
+            let color = if i == self.sel {
+                Color::WHITE
+            } else {
+                Color::RGB(100, 105, 125)
+            };
+            let y = WIN_H as f32 - 250.0 + i as f32 * 50.0;
+            draw_text(cnv, s, cx, y, 4.0, color, Align::Center);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use sdl3::pixels::Color;
+
+/// The results of completing a level, which are worth points.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Score {
+    /// Frames the level was completed in.
+    pub time: u32,
+
+    /// Enemies killed by the player.
+    pub kills: u32,
+
+    /// Extra kills made along with another by a single weapon in the same frame, like with one laser.
+    pub combo: u32,
+
+    /// Deaths before the level was completed.
+    pub deaths: u32,
+}
+
+/// A grade for a score, against the level's par.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Rank {
+    S,
+    A,
+    B,
+    C,
+}
+
+impl Rank {
+    /// Returns the rank's letter.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::S => "S",
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+        }
+    }
+
+    /// Returns the color the rank is shown in.
+    pub fn color(self) -> Color {
+        match self {
+            Self::S => Color::RGB(255, 215, 0),
+            Self::A => Color::RGB(25, 255, 200),
+            Self::B => Color::RGB(50, 150, 255),
+            Self::C => Color::RGB(100, 105, 125),
+        }
+    }
+}
+
+impl Score {
+    /// Points for each kill.
+    const KILL: u32 = 100;
+
+    /// Points for each extra kill in a combo.
+    const COMBO: u32 = 50;
+
+    /// Points for completing a level in its par time, scaling with how much faster or slower it was.
+    const TIME: u32 = 1000;
+
+    /// Points lost for each death.
+    const DEATH: u32 = 200;
+
+    /// Returns the points the score is worth, on a level with the given par time.
+    pub fn points(&self, par: u32) -> u32 {
+        let time = Self::TIME * par / self.time.max(1);
+        (self.kills * Self::KILL + self.combo * Self::COMBO + time)
+            .saturating_sub(self.deaths * Self::DEATH)
+    }
+
+    /// Returns the score's rank, on a level with the given par time.
+    pub fn rank(&self, par: u32) -> Rank {
+        // Clearing the level in its par time without combos or deaths just makes an S.
+        let at_par = Self {
+            time: par,
+            combo: 0,
+            deaths: 0,
+            ..*self
+        };
+        match self.points(par) * 4 / at_par.points(par).max(1) {
+            4.. => Rank::S,
+            3 => Rank::A,
+            2 => Rank::B,
+            _ => Rank::C,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Par time of the levels in these tests.
+    const PAR: u32 = 600;
+
+    /// Returns a score with no kills or deaths, completed in the given time.
+    fn timed(time: u32) -> Score {
+        Score {
+            time,
+            kills: 0,
+            combo: 0,
+            deaths: 0,
+        }
+    }
+
+    #[test]
+    fn counts_kills_and_combos() {
+        let s = Score {
+            kills: 3,
+            combo: 2,
+            ..timed(PAR)
+        };
+        assert_eq!(s.points(PAR), 3 * 100 + 2 * 50 + 1000);
+    }
+
+    #[test]
+    fn deaths_dont_go_below_zero() {
+        let s = Score {
+            deaths: 1,
+            ..timed(PAR)
+        };
+        assert_eq!(s.points(PAR), 800);
+
+        let s = Score {
+            deaths: 10,
+            ..timed(PAR)
+        };
+        assert_eq!(s.points(PAR), 0);
+    }
+
+    #[test]
+    fn zero_time_doesnt_divide_by_zero() {
+        assert_eq!(timed(0).points(PAR), timed(1).points(PAR));
+    }
+
+    #[test]
+    fn ranks_against_par() {
+        // Without kills, finishing at par is worth 1000 points.
+        for (time, rank) in [
+            (PAR, Rank::S),
+            (PAR + 1, Rank::A),
+            (800, Rank::A),
+            (801, Rank::B),
+            (1200, Rank::B),
+            (1201, Rank::C),
+        ] {
+            assert_eq!(timed(time).rank(PAR), rank, "time {time}");
+        }
+    }
+}
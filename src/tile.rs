@@ -1,4 +1,8 @@
-use super::{entity::Entity, geometry::BBox};
+use super::{
+    config::GRAVITY,
+    entity::Entity,
+    geometry::{BBox, Vec2},
+};
 use sdl3::pixels::Color;
 
 #[derive(Clone, Copy, Debug)]
@@ -7,8 +11,87 @@ pub enum TileID {
     Blck, // Block: simple solid.
     VPad, // Vertical launch pad: launches the player away vertically.
     HPad, // Horiontal launch pad: launches the player away horizontally.
-    Ladr, // Ladder: allows the player to jump off of it.
+    Ladr, // Ladder: allows the player to jump off of its sides.
     Fire, // Fire: kills entities that touch it.
+    Icey, // Ice: slippery ground with very low acceleration and decceleration.
+    CnvL, // Left conveyor: carries entities standing on it to the left.
+    CnvR, // Right conveyor: carries entities standing on it to the right.
+    Stky, // Sticky floor: caps the speed and jump height of entities standing on it.
+    Watr, // Water: non-solid liquid that entities can swim in.
+    Acid, // Acid: non-solid liquid that kills entities that enter it.
+    Flip, // Gravity flipper: flips gravity upwards when touched from above, back when touched from below.
+    LowG, // Low gravity zone: weakens gravity for entities inside it.
+    RevG, // Reversed gravity zone: makes gravity pull upwards for entities inside it.
+    LftG, // Left gravity zone: makes gravity pull left for entities inside it.
+    RgtG, // Right gravity zone: makes gravity pull right for entities inside it.
+    SlpR, // Steep slope: a 45° ramp rising to the right.
+    SlpL, // Steep slope: a 45° ramp rising to the left.
+    SlgR, // Gentle slope: a 22.5° ramp rising to the right.
+    SlgL, // Gentle slope: a 22.5° ramp rising to the left.
+    MirR, // Mirror ramp: a 45° ramp rising to the right with a slope that reflects lasers.
+    MirL, // Mirror ramp: a 45° ramp rising to the left with a slope that reflects lasers.
+    Refl, // Reflector: a block with sides that reflect lasers.
+    Glas, // Glass: a block that lets lasers through.
+    Chck, // Checkpoint: a zone that becomes the player's respawn point once touched.
+}
+
+/// How entities interact with a tile's area.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    Solid,  // Entities collide with it.
+    Liquid, // Entities can enter it, and it is drawn over them with a surface.
+    Zone,   // Entities can enter it, and it is drawn under them.
+}
+
+/// The ground physics of a tile's top, applied to entities standing on it.
+#[derive(Clone, Copy, Debug)]
+pub struct Surface {
+    /// Horizontal acceleration multiplier.
+    pub acc: f32,
+
+    /// Horizontal decceleration multiplier.
+    pub dec: f32,
+
+    /// Max horizontal speed.
+    pub cap: f32,
+
+    /// Jump velocity multiplier.
+    pub jmp: f32,
+
+    /// Horizontal velocity added to the entity's movement.
+    pub conv: f32,
+}
+
+/// The shape of a tile inside its bounding-box.
+#[derive(Clone, Copy, Debug)]
+pub enum Shape {
+    Rect,       // Fills the bounding-box.
+    Slope(f32), // Ramp rising to the right by the given height per width, or to the left if negative.
+}
+
+/// What a tile's sides let entities do when touching them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Wall {
+    No,    // Nothing; entities fall past it normally.
+    Slide, // Entities pressing into it slide down slowly, and can wall jump away from it.
+    Climb, // Entities touching it can jump straight up off of it, like from the ground.
+}
+
+/// What a tile does to lasers that hit it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Beam {
+    Stop,     // Stops lasers.
+    Pass,     // Lets lasers through.
+    Absorb,   // Lets lasers through, absorbing them if they travel too far through it.
+    Activate, // Stops lasers, and runs its collision callback on entities in front of the side that was hit.
+}
+
+/// Which of a tile's sides reflect lasers.
+#[derive(Clone, Copy, Debug)]
+pub enum Mirror {
+    No,   // Stops lasers.
+    All,  // Reflects lasers off every side.
+    Ramp, // Reflects lasers off the slope of a ramp, and stops them on other sides.
 }
 
 #[derive(Debug)]
@@ -16,8 +99,26 @@ pub struct Tile {
     /// The callback that is called when an entity collides with the tile.
     col_cb: fn(&BBox, &mut dyn Entity),
 
+    /// What the tile does to lasers that hit it.
+    beam: Beam,
+
     /// The color of the tile.
     color: Color,
+
+    /// The tile's ground physics.
+    surface: Surface,
+
+    /// What the tile's sides let entities do when touching them.
+    wall: Wall,
+
+    /// How entities interact with the tile's area.
+    kind: Kind,
+
+    /// The shape of the tile inside its bounding-box.
+    shape: Shape,
+
+    /// Which of the tile's sides reflect lasers.
+    mirror: Mirror,
 }
 
 impl TileID {
@@ -26,23 +127,160 @@ impl TileID {
         Tile::TILES[self as usize].color
     }
 
+    /// Returns the tile's ground physics.
+    pub fn get_surface(self) -> Surface {
+        Tile::TILES[self as usize].surface
+    }
+
+    /// Returns what the tile's sides let entities do when touching them.
+    pub fn get_wall(self) -> Wall {
+        Tile::TILES[self as usize].wall
+    }
+
+    /// Returns true if entities collide with the tile, else false.
+    pub fn is_solid(self) -> bool {
+        Tile::TILES[self as usize].kind == Kind::Solid
+    }
+
+    /// Returns true if the tile is a liquid, else false.
+    pub fn is_liquid(self) -> bool {
+        Tile::TILES[self as usize].kind == Kind::Liquid
+    }
+
+    /// Returns the tile's slope if it is a ramp, else `None`.
+    pub fn get_slope(self) -> Option<f32> {
+        match Tile::TILES[self as usize].shape {
+            Shape::Rect => None,
+            Shape::Slope(k) => Some(k),
+        }
+    }
+
+    /// Returns the tile's top at the given x in the bounding-box `b`.
+    pub fn top(self, b: &BBox, x: f32) -> f32 {
+        match self.get_slope() {
+            None => b.y,
+            Some(k) => {
+                let x = x.clamp(b.x, b.a);
+                let run = if k > 0.0 { x - b.x } else { b.a - x };
+                (b.b - run * k.abs()).clamp(b.y, b.b)
+            }
+        }
+    }
+
+    /// Returns what the tile does to lasers that hit it.
+    pub fn get_beam(self) -> Beam {
+        Tile::TILES[self as usize].beam
+    }
+
+    /// Runs the tile's collision callback on the entity if it is in front of the side with the given outwards
+    /// normal, for when a laser activates the tile.
+    pub fn activate(self, b: &BBox, n: Vec2, e: &mut dyn Entity) {
+        let r = Tile::REACH;
+        let zone = BBox::new(
+            if n.x < 0.0 { b.x - r } else { b.x },
+            if n.y < 0.0 { b.y - r } else { b.y },
+            if n.x > 0.0 { b.a + r } else { b.a },
+            if n.y > 0.0 { b.b + r } else { b.b },
+        );
+
+        if zone.collides_with_rect(&e.get_body()) {
+            self.do_collision(b, e);
+        }
+    }
+
+    /// Returns true if the tile's side with the given outwards normal reflects lasers, else false.
+    pub fn reflects(self, n: Vec2) -> bool {
+        match Tile::TILES[self as usize].mirror {
+            Mirror::No => false,
+            Mirror::All => true,
+            Mirror::Ramp => n.x != 0.0 && n.y != 0.0,
+        }
+    }
+
+    /// Casts a ray from `o` in the direction `d` at the tile in the bounding-box `b`.
+    /// Returns the distances along the ray at which it enters and exits, and the outwards normal of the side it
+    /// enters through, or `None` if it misses.
+    pub fn ray(self, b: &BBox, o: Vec2, d: Vec2) -> Option<(f32, f32, Vec2)> {
+        let (mut near, mut far) = b.ray(o, d)?;
+        let mut n = b.normal_at(o + d * near);
+
+        if let Some(k) = self.get_slope() {
+            // Ramps are the part of the bounding-box where `y + k * x >= c`.
+            let c = b.b + k * if k > 0.0 { b.x } else { b.a };
+            let f = o.y + k * o.x - c;
+            let df = d.y + k * d.x;
+
+            if df == 0.0 {
+                if f < 0.0 {
+                    return None;
+                }
+            } else if df > 0.0 {
+                if -f / df > near {
+                    near = -f / df;
+                    n = Vec2::new(-k, -1.0).norm();
+                }
+            } else {
+                far = far.min(-f / df);
+            }
+        }
+
+        (near <= far).then_some((near, far, n))
+    }
+
     /// Calls the tile's collision function on the entity.
     pub fn do_collision(self, r: &BBox, e: &mut dyn Entity) {
         (Tile::TILES[self as usize].col_cb)(r, e)
     }
 }
 
+impl Surface {
+    /// Regular ground physics, also used while in the air.
+    pub const NONE: Self = Self {
+        acc: 1.0,
+        dec: 1.0,
+        cap: f32::INFINITY,
+        jmp: 1.0,
+        conv: 0.0,
+    };
+
+    /// Conveyor belt speed.
+    const CONV: f32 = 3.0;
+
+    /// Returns the x-velocity `vx` accelerated towards `target` by the given base acceleration.
+    /// The target is capped to the surface's max speed.
+    pub fn accelerate(&self, vx: f32, target: f32, acc: f32) -> f32 {
+        let target = target.clamp(-self.cap, self.cap);
+        let acc = acc * self.acc;
+        vx + (target - vx).clamp(-acc, acc)
+    }
+}
+
 impl Tile {
-    const TILES: [Tile; 5] = [
+    /// Slope of 45° ramps.
+    const STEEP: f32 = 1.0;
+
+    /// Slope of 22.5° ramps.
+    const GENTLE: f32 = 0.414_213_57;
+
+    /// How far in front of the side hit by a laser activated tiles reach.
+    const REACH: f32 = 120.0;
+
+    const TILES: [Tile; 25] = [
         // `Blck` (block):
         Tile {
             col_cb: |_, _| {},
+            beam: Beam::Stop,
             color: Color {
                 r: 100,
                 g: 105,
                 b: 125,
                 a: 255,
             },
+            surface: Surface::NONE,
+            wall: Wall::Slide,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
         },
         // `VPad` (vertical launch pad):
         Tile {
@@ -53,49 +291,407 @@ impl Tile {
                 // Prevents jumping on the pad from being a normal jump.
                 e.set_on_ground(false);
             },
+            beam: Beam::Activate,
             color: Color {
                 r: 25,
                 g: 255,
                 b: 200,
                 a: 255,
             },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
         },
         // `HPad` (horizontal launch pad):
         Tile {
             col_cb: |b, e| {
                 // Launch left if to the left and right if to the riht.
                 e.set_vx(if e.get_body().center().x >= b.center().x {
-                    -25.0
-                } else {
                     25.0
+                } else {
+                    -25.0
                 })
             },
+            beam: Beam::Activate,
             color: Color {
                 r: 25,
                 g: 200,
                 b: 255,
                 a: 255,
             },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
         },
         // `Ladr` (ladder):
         Tile {
-            col_cb: |_, e| e.set_on_ground(true),
+            col_cb: |_, _| {},
+            beam: Beam::Pass,
             color: Color {
                 r: 255,
                 g: 225,
                 b: 125,
                 a: 255,
             },
+            surface: Surface::NONE,
+            wall: Wall::Climb,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
         },
         // `Fire` (fire):
         Tile {
             col_cb: |_, e| e.kill(),
+            beam: Beam::Pass,
             color: Color {
                 r: 100,
                 g: 0,
                 b: 20,
                 a: 255,
             },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `Icey` (ice):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 175,
+                g: 230,
+                b: 255,
+                a: 255,
+            },
+            surface: Surface {
+                acc: 0.1,
+                dec: 0.03,
+                ..Surface::NONE
+            },
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `CnvL` (left conveyor):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 150,
+                g: 140,
+                b: 60,
+                a: 255,
+            },
+            surface: Surface {
+                conv: -Surface::CONV,
+                ..Surface::NONE
+            },
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `CnvR` (right conveyor):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 175,
+                g: 165,
+                b: 60,
+                a: 255,
+            },
+            surface: Surface {
+                conv: Surface::CONV,
+                ..Surface::NONE
+            },
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `Stky` (sticky floor):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 90,
+                g: 60,
+                b: 35,
+                a: 255,
+            },
+            surface: Surface {
+                cap: 3.5,
+                jmp: 0.6,
+                ..Surface::NONE
+            },
+            wall: Wall::Slide,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `Watr` (water):
+        Tile {
+            col_cb: |_, e| e.set_submerged(true),
+            beam: Beam::Absorb,
+            color: Color {
+                r: 40,
+                g: 90,
+                b: 220,
+                a: 120,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Liquid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `Acid` (acid):
+        Tile {
+            col_cb: |_, e| e.kill(),
+            beam: Beam::Absorb,
+            color: Color {
+                r: 120,
+                g: 220,
+                b: 40,
+                a: 160,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Liquid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `Flip` (gravity flipper):
+        Tile {
+            col_cb: |b, e| e.set_flipped(e.get_body().center().y < b.center().y),
+            beam: Beam::Stop,
+            color: Color {
+                r: 175,
+                g: 90,
+                b: 255,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `LowG` (low gravity zone):
+        Tile {
+            col_cb: |_, e| e.set_gravity(Vec2::new(0.0, GRAVITY * 0.35)),
+            beam: Beam::Pass,
+            color: Color {
+                r: 200,
+                g: 200,
+                b: 255,
+                a: 30,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Zone,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `RevG` (reversed gravity zone):
+        Tile {
+            col_cb: |_, e| e.set_gravity(Vec2::new(0.0, -GRAVITY)),
+            beam: Beam::Pass,
+            color: Color {
+                r: 175,
+                g: 90,
+                b: 255,
+                a: 30,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Zone,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `LftG` (left gravity zone):
+        Tile {
+            col_cb: |_, e| e.set_gravity(Vec2::new(-GRAVITY, 0.0)),
+            beam: Beam::Pass,
+            color: Color {
+                r: 255,
+                g: 150,
+                b: 90,
+                a: 30,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Zone,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `RgtG` (right gravity zone):
+        Tile {
+            col_cb: |_, e| e.set_gravity(Vec2::new(GRAVITY, 0.0)),
+            beam: Beam::Pass,
+            color: Color {
+                r: 90,
+                g: 255,
+                b: 150,
+                a: 30,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Zone,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `SlpR` (steep slope rising right):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 100,
+                g: 105,
+                b: 125,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Slope(Self::STEEP),
+            mirror: Mirror::No,
+        },
+        // `SlpL` (steep slope rising left):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 100,
+                g: 105,
+                b: 125,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Slope(-Self::STEEP),
+            mirror: Mirror::No,
+        },
+        // `SlgR` (gentle slope rising right):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 100,
+                g: 105,
+                b: 125,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Slope(Self::GENTLE),
+            mirror: Mirror::No,
+        },
+        // `SlgL` (gentle slope rising left):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 100,
+                g: 105,
+                b: 125,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Slope(-Self::GENTLE),
+            mirror: Mirror::No,
+        },
+        // `MirR` (mirror ramp rising right):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 200,
+                g: 230,
+                b: 255,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Slope(Self::STEEP),
+            mirror: Mirror::Ramp,
+        },
+        // `MirL` (mirror ramp rising left):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 200,
+                g: 230,
+                b: 255,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Solid,
+            shape: Shape::Slope(-Self::STEEP),
+            mirror: Mirror::Ramp,
+        },
+        // `Refl` (reflector):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Stop,
+            color: Color {
+                r: 170,
+                g: 190,
+                b: 210,
+                a: 255,
+            },
+            surface: Surface::NONE,
+            wall: Wall::Slide,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::All,
+        },
+        // `Glas` (glass):
+        Tile {
+            col_cb: |_, _| {},
+            beam: Beam::Pass,
+            color: Color {
+                r: 190,
+                g: 230,
+                b: 255,
+                a: 90,
+            },
+            surface: Surface::NONE,
+            wall: Wall::Slide,
+            kind: Kind::Solid,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
+        },
+        // `Chck` (checkpoint):
+        Tile {
+            col_cb: |b, e| e.touch_checkpoint(b),
+            beam: Beam::Pass,
+            color: Color {
+                r: 255,
+                g: 240,
+                b: 120,
+                a: 40,
+            },
+            surface: Surface::NONE,
+            wall: Wall::No,
+            kind: Kind::Zone,
+            shape: Shape::Rect,
+            mirror: Mirror::No,
         },
     ];
 }
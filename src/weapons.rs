@@ -0,0 +1,13 @@
+pub mod bolt;
+pub mod laser;
+pub mod melee;
+pub mod shot;
+pub mod spread;
+pub mod weapon;
+
+pub use bolt::Bolt;
+pub use laser::Laser;
+pub use melee::Melee;
+pub use shot::Shot;
+pub use spread::Spread;
+pub use weapon::{Weapon, WeaponID};
@@ -0,0 +1,89 @@
+use super::{shot::hit_with_shots, Shot, Weapon};
+use crate::{enemies::EnemyVec, geometry::Vec2, map::TileIter};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// A weapon that fires slow bolts which home in on the nearest enemy.
+pub struct Bolt {
+    /// The bolts in flight.
+    shots: Vec<Shot>,
+
+    /// The frames left before the weapon can fire again.
+    cool: u32,
+}
+
+impl Bolt {
+    /// Frames between shots.
+    const COOLDOWN: u32 = 50;
+
+    /// Damage dealt by each bolt.
+    const DAMAGE: u32 = 2;
+
+    /// Bolt speed.
+    const SPEED: f32 = 4.0;
+
+    /// How sharply bolts turn towards enemies each frame.
+    const TURN: f32 = 0.08;
+
+    /// Bolt size (width and height).
+    const S: f32 = 12.0;
+
+    /// Frames a bolt lasts for.
+    const LIFE: u32 = 300;
+
+    /// Bolt color.
+    const COLOR: Color = Color {
+        r: 100,
+        g: 255,
+        b: 150,
+        a: 255,
+    };
+
+    /// Returns a new homing bolt weapon, ready to fire.
+    pub fn new() -> Self {
+        Self {
+            shots: Vec::new(),
+            cool: 0,
+        }
+    }
+}
+
+impl Weapon for Bolt {
+    fn is_ready(&self) -> bool {
+        self.cool == 0
+    }
+
+    fn fire(&mut self, beg: Vec2, dir: Vec2, _: TileIter, _: f32) {
+        self.shots.push(Shot::new(
+            beg,
+            dir.norm() * Self::SPEED,
+            Self::S,
+            Self::LIFE,
+        ));
+        self.cool = Self::COOLDOWN;
+    }
+
+    fn update(&mut self, map: TileIter) {
+        self.cool = self.cool.saturating_sub(1);
+        self.shots.iter_mut().for_each(|s| s.update(map.clone()));
+        self.shots.retain(|s| s.is_alive());
+    }
+
+    /// Steers the bolts towards the nearest enemy, then damages the enemies they hit.
+    fn hit_enemies(&mut self, e: &mut EnemyVec) {
+        for s in &mut self.shots {
+            let c = s.get_body().center();
+            if let Some(p) = e
+                .iter()
+                .map(|e| e.get_body().center())
+                .min_by(|a, b| (*a - c).len().total_cmp(&(*b - c).len()))
+            {
+                s.steer(p, Self::TURN);
+            }
+        }
+        hit_with_shots(&mut self.shots, e, Self::DAMAGE);
+    }
+
+    fn draw(&self, cnv: &mut Canvas<Window>) {
+        self.shots.iter().for_each(|s| s.draw(cnv, Self::COLOR));
+    }
+}
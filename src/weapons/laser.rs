@@ -0,0 +1,398 @@
+use super::Weapon;
+use crate::{
+    config::WIN_B,
+    enemies::EnemyVec,
+    geometry::{BBox, Rect, Vec2},
+    map::TileIter,
+    tile::{Beam, TileID},
+};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// A laser the player can shoot enemies with.
+#[derive(Clone, Copy)]
+pub struct Laser {
+    /// The laser's points: its beggining, the points it bounced at, and its ending.
+    pts: [Vec2; Self::PTS],
+
+    /// The number of points used.
+    n: usize,
+
+    /// Whether the laser passes through enemies, or stops at the first one.
+    pierce: bool,
+
+    /// The tile the laser activated and the outwards normal of the side it hit, until it's taken.
+    pad: Option<(BBox, TileID, Vec2)>,
+
+    /// How far from 0 (uncharged) to 1 (fully charged) the laser was charged.
+    charge: f32,
+
+    /// The laser's timer.
+    time: u8,
+}
+
+impl Laser {
+    /// How far the laser can travel through liquids.
+    const LIQUID_RANGE: f32 = 120.0;
+
+    /// The max number of times the laser can bounce off of mirrors.
+    const MAX_BOUNCES: usize = 8;
+
+    /// The max number of points in the laser.
+    const PTS: usize = Self::MAX_BOUNCES + 2;
+
+    /// Damage dealt to each enemy hit, enough to kill any enemy, so each is only hit once per shot.
+    const DAMAGE: u32 = 3;
+
+    /// Width of a fully charged laser.
+    const MAX_W: f32 = 9.0;
+
+    /// How much the timer drops every frame for uncharged and fully charged lasers.
+    const FADE: (f32, f32) = (15.0, 4.0);
+
+    /// Distance that tiles must be past a bounce to be hit, so the laser doesn't hit the mirror it bounced off of.
+    const EPS: f32 = 0.01;
+
+    /// Returns a new laser with the given base and direction with a full timer.
+    /// The direction can be of any non-zero length, and the charge goes from 0 (uncharged) to 1 (fully charged).
+    /// Charged lasers are wider and last longer, hitting enemies that move into them for longer.
+    pub fn new(beg: Vec2, dir: Vec2, map: TileIter, pierce: bool, charge: f32) -> Self {
+        let (pts, n, pad) = Self::cast(beg, dir.norm(), map);
+
+        Self {
+            pts,
+            n,
+            pierce,
+            pad,
+            charge,
+            time: 255,
+        }
+    }
+
+    /// Returns ann inactive laser.
+    pub fn new_inactive() -> Self {
+        Self {
+            pts: [Vec2::zero(); Self::PTS],
+            n: 0,
+            pierce: false,
+            pad: None,
+            charge: 0.0,
+            time: 0,
+        }
+    }
+
+    /// True if the laser is active and any of its segments hit the given rectangle, else false.
+    pub fn hits_rect(&self, r: &Rect) -> bool {
+        self.is_active()
+            && self.pts[..self.n]
+                .windows(2)
+                .any(|s| Self::segment_hits(s[0], s[1], &self.reach(r)))
+    }
+
+    /// True if the laser is active (timer isn't done), else false.
+    pub fn is_active(&self) -> bool {
+        self.time != 0
+    }
+
+    /// Returns the laser's width.
+    fn width(&self) -> f32 {
+        1.0 + (Self::MAX_W - 1.0) * self.charge
+    }
+
+    /// Returns the given rectangle grown by the laser's width, so hitting it means touching the beam's edge.
+    fn reach(&self, r: &Rect) -> Rect {
+        let w = self.width() / 2.0;
+        Rect::new(r.x - w, r.y - w, r.w + w * 2.0, r.h + w * 2.0)
+    }
+
+    /// Returns the distance along the laser at which it first hits the given rectangle, or `None` if it misses.
+    fn first_hit(&self, r: &Rect) -> Option<f32> {
+        let r = &self.reach(r);
+        let mut acc = 0.0;
+
+        for s in self.pts[..self.n].windows(2) {
+            let d = s[1] - s[0];
+            let len = d.len();
+
+            if Self::segment_hits(s[0], s[1], r) {
+                let near = r.bbox().ray(s[0], d.norm()).map_or(0.0, |(near, _)| near);
+                return Some(acc + near.max(0.0));
+            }
+            acc += len;
+        }
+
+        None
+    }
+
+    /// Cuts the laser short at the given distance along it.
+    fn cut(&mut self, dist: f32) {
+        let mut acc = 0.0;
+
+        for i in 1..self.n {
+            let d = self.pts[i] - self.pts[i - 1];
+            let len = d.len();
+
+            if acc + len >= dist {
+                if len > 0.0 {
+                    self.pts[i] = self.pts[i - 1] + d.norm() * (dist - acc);
+                }
+                self.n = i + 1;
+                return;
+            }
+            acc += len;
+        }
+    }
+
+    /// True if the segment from `beg` to `end` crosses the given rectangle, else false.
+    fn segment_hits(beg: Vec2, end: Vec2, r: &Rect) -> bool {
+        let d = end - beg;
+        let len = d.len();
+
+        if len == 0.0 {
+            return r.bbox().contains(beg);
+        }
+        r.bbox()
+            .ray(beg, d.norm())
+            .is_some_and(|(near, far)| near <= len && far >= 0.0)
+    }
+
+    /// Returns the laser's points and their count based on its start and unit direction.
+    /// Stops the laser or lets it through tiles depending on their beam behaviour, bounces it off of mirrors, and
+    /// absorbs it some way into liquids.
+    /// Also returns the tile the laser activated at its end, if any.
+    fn cast(
+        beg: Vec2,
+        dir: Vec2,
+        map: TileIter,
+    ) -> ([Vec2; Self::PTS], usize, Option<(BBox, TileID, Vec2)>) {
+        let mut pts = [beg; Self::PTS];
+        let (mut o, mut d) = (beg, dir);
+
+        for i in 1..Self::PTS {
+            // Start with the distance to the window's edge.
+            let mut dist = WIN_B.ray(o, d).map_or(0.0, |(_, far)| far);
+            let mut stop = None;
+
+            for (bbox, tile) in map.clone() {
+                // Get the near and far distances of the tile along the laser, if it is in its way.
+                let Some((near, far, n)) = tile.ray(bbox, o, d) else {
+                    continue;
+                };
+
+                match tile.get_beam() {
+                    Beam::Stop | Beam::Activate => {
+                        if near > Self::EPS && near < dist {
+                            dist = near;
+                            stop = Some((*bbox, *tile, n));
+                        }
+                    }
+                    Beam::Absorb => {
+                        // Absorb the laser if it would travel too far through the tile.
+                        let cut = near.max(0.0) + Self::LIQUID_RANGE;
+                        if far > 0.0 && cut < far && cut < dist {
+                            dist = cut;
+                            stop = None;
+                        }
+                    }
+                    Beam::Pass => {}
+                }
+            }
+
+            o = o + d * dist;
+            pts[i] = o;
+
+            // Reflect off of mirrors, else stop, activating the tile if it can be.
+            match stop {
+                Some((_, t, n)) if t.reflects(n) => d = d - n * (2.0 * d.dot(n)),
+                Some((b, t, n)) if t.get_beam() == Beam::Activate => {
+                    return (pts, i + 1, Some((b, t, n)))
+                }
+                _ => return (pts, i + 1, None),
+            }
+        }
+
+        (pts, Self::PTS, None)
+    }
+}
+
+impl Weapon for Laser {
+    fn is_ready(&self) -> bool {
+        !self.is_active()
+    }
+
+    fn uses_energy(&self) -> bool {
+        true
+    }
+
+    fn fire(&mut self, beg: Vec2, dir: Vec2, map: TileIter, charge: f32) {
+        *self = Self::new(beg, dir, map, self.pierce, charge);
+    }
+
+    fn update(&mut self, _: TileIter) {
+        let (lo, hi) = Self::FADE;
+        let fade = lo + (hi - lo) * self.charge;
+        self.time = self.time.saturating_sub(fade as u8);
+    }
+
+    /// Damages the enemies touching the laser while it's active, including ones that move into it after it was shot.
+    /// Unless piercing, only the first enemy along the laser is hit, and the laser is cut short at it.
+    fn hit_enemies(&mut self, e: &mut EnemyVec) {
+        if !self.is_active() {
+            return;
+        }
+
+        if self.pierce {
+            e.iter_mut()
+                .filter(|e| self.hits_rect(&e.get_body()))
+                .for_each(|e| e.damage(Self::DAMAGE));
+        } else if let Some((i, dist)) = e
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| Some((i, self.first_hit(&e.get_body())?)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        {
+            e[i].damage(Self::DAMAGE);
+            self.cut(dist);
+
+            // The enemy blocked the laser from reaching the end.
+            self.pad = None;
+        }
+        e.retain(|e| e.is_alive());
+    }
+
+    fn take_activated(&mut self) -> Option<(BBox, TileID, Vec2)> {
+        self.pad.take()
+    }
+
+    fn set_pierce(&mut self, b: bool) {
+        self.pierce = b;
+    }
+
+    fn draw(&self, cnv: &mut Canvas<Window>) {
+        if self.is_active() {
+            // Piercing lasers are tinted.
+            cnv.set_draw_color(Color {
+                r: 255,
+                g: if self.pierce { 150 } else { 255 },
+                b: 255,
+                a: self.time,
+            });
+            // Wide lasers are drawn as parallel lines.
+            let w = self.width() as i32;
+            for s in self.pts[..self.n].windows(2) {
+                let side = Vec2::new(s[0].y - s[1].y, s[1].x - s[0].x).norm();
+                for i in 0..w {
+                    let off = side * (i as f32 - (w - 1) as f32 / 2.0);
+                    cnv.draw_line(s[0] + off, s[1] + off).unwrap();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enemies::Sitter, entity::Entity};
+
+    /// Returns a laser shot right along `y = 50` through an empty map.
+    fn shoot(pierce: bool) -> Laser {
+        Laser::new(
+            Vec2::new(0.0, 50.0),
+            Vec2::new(1.0, 0.0),
+            [].iter(),
+            pierce,
+            0.0,
+        )
+    }
+
+    /// Returns a row of sitters in the way of `shoot`'s laser.
+    fn row() -> EnemyVec {
+        (1..=3)
+            .map(|i| Box::new(Sitter::new(i as f32 * 100.0, 40.0)) as Box<dyn Entity>)
+            .collect()
+    }
+
+    #[test]
+    fn stops_at_first_enemy() {
+        let mut l = shoot(false);
+        let mut e = row();
+        l.hit_enemies(&mut e);
+
+        // Only the closest sitter dies, and the laser no longer reaches the rest.
+        assert_eq!(e.len(), 2);
+        assert!(e.iter().all(|e| e.get_body().x > 100.0));
+        assert!(e.iter().all(|e| !l.hits_rect(&e.get_body())));
+    }
+
+    #[test]
+    fn reflects_off_mirror_ramps() {
+        // A mirror ramp rising to the right, with its slope facing the laser.
+        static MAP: [(BBox, TileID); 1] = [(BBox::new(100.0, 0.0, 200.0, 100.0), TileID::MirR)];
+        let l = Laser::new(
+            Vec2::new(0.0, 50.0),
+            Vec2::new(1.0, 0.0),
+            MAP.iter(),
+            false,
+            0.0,
+        );
+
+        // The laser bounces straight up off the slope, up to the window's edge.
+        let pts = [
+            Vec2::new(0.0, 50.0),
+            Vec2::new(150.0, 50.0),
+            Vec2::new(150.0, 0.0),
+        ];
+        assert_eq!(l.n, pts.len());
+        assert!(pts.iter().zip(l.pts).all(|(p, q)| (*p - q).len() < 0.001));
+    }
+
+    #[test]
+    fn charged_hits_once() {
+        let mut l = shoot(false);
+        l.charge = 1.0;
+        let mut e = row();
+        l.hit_enemies(&mut e);
+        l.hit_enemies(&mut e);
+
+        // The closest sitter is hit once, and blocks the rest.
+        assert_eq!(e.len(), 2);
+
+        // Long after an uncharged laser would be gone, a sitter moving into the beam is still hit.
+        let mut short = shoot(false);
+        for _ in 0..30 {
+            l.update([].iter());
+            short.update([].iter());
+        }
+        assert!(l.is_active() && !short.is_active());
+        e.push(Box::new(Sitter::new(50.0, 40.0)));
+        l.hit_enemies(&mut e);
+
+        assert_eq!(e.len(), 2);
+        assert!(e.iter().all(|e| e.get_body().x > 100.0));
+    }
+
+    #[test]
+    fn hits_enemies_entering_it() {
+        let mut l = shoot(true);
+        let mut e = EnemyVec::new();
+        l.hit_enemies(&mut e);
+
+        // A sitter moving into the laser a few frames after it was shot.
+        for _ in 0..3 {
+            l.update([].iter());
+        }
+        e.push(Box::new(Sitter::new(50.0, 40.0)));
+        l.hit_enemies(&mut e);
+
+        assert!(e.is_empty());
+    }
+
+    #[test]
+    fn piercing_kills_all() {
+        let mut l = shoot(true);
+        let mut e = row();
+        l.hit_enemies(&mut e);
+
+        assert!(e.is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+use super::Weapon;
+use crate::{
+    enemies::EnemyVec,
+    geometry::{Rect, Vec2},
+    map::TileIter,
+};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// A short-range swing that hits every enemy in front of the player.
+pub struct Melee {
+    /// The area the swing hits.
+    body: Rect,
+
+    /// The frames left of the swing's visual.
+    time: u32,
+
+    /// Tracks whether the swing has already dealt its damage.
+    hit: bool,
+
+    /// The frames left before the weapon can swing again.
+    cool: u32,
+}
+
+impl Melee {
+    /// Frames between swings.
+    const COOLDOWN: u32 = 20;
+
+    /// Damage dealt to each enemy hit.
+    const DAMAGE: u32 = 2;
+
+    /// Distance from the player's center to the swing's center.
+    const RANGE: f32 = 36.0;
+
+    /// Swing size (width and height).
+    const S: f32 = 40.0;
+
+    /// Frames the swing is visible for.
+    const TIME: u32 = 8;
+
+    /// Returns a new melee weapon, ready to swing.
+    pub fn new() -> Self {
+        Self {
+            body: Rect::square(0.0, 0.0, Self::S),
+            time: 0,
+            hit: true,
+            cool: 0,
+        }
+    }
+}
+
+impl Weapon for Melee {
+    fn is_ready(&self) -> bool {
+        self.cool == 0
+    }
+
+    fn fire(&mut self, beg: Vec2, dir: Vec2, _: TileIter, _: f32) {
+        let c = beg + dir.norm() * Self::RANGE;
+        self.body = Rect::square(c.x - Self::S / 2.0, c.y - Self::S / 2.0, Self::S);
+        self.time = Self::TIME;
+        self.hit = false;
+        self.cool = Self::COOLDOWN;
+    }
+
+    fn update(&mut self, _: TileIter) {
+        self.cool = self.cool.saturating_sub(1);
+        self.time = self.time.saturating_sub(1);
+    }
+
+    fn hit_enemies(&mut self, e: &mut EnemyVec) {
+        if self.hit {
+            return;
+        }
+        self.hit = true;
+
+        e.iter_mut()
+            .filter(|e| e.get_body().collides_with(&self.body))
+            .for_each(|e| e.damage(Self::DAMAGE));
+        e.retain(|e| e.is_alive());
+    }
+
+    fn draw(&self, cnv: &mut Canvas<Window>) {
+        if self.time > 0 {
+            cnv.set_draw_color(Color {
+                r: 255,
+                g: 255,
+                b: 255,
+                a: (self.time * 255 / Self::TIME) as u8 / 2,
+            });
+            cnv.fill_rect(&self.body).unwrap();
+        }
+    }
+}
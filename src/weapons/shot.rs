@@ -0,0 +1,86 @@
+use crate::{
+    config::WIN_B,
+    enemies::EnemyVec,
+    geometry::{Rect, Vec2},
+    map::TileIter,
+};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// A projectile fired by a weapon, which flies until it hits something or runs out of time.
+#[derive(Clone, Copy)]
+pub struct Shot {
+    /// The shot's body.
+    body: Rect,
+
+    /// The shot's velocity.
+    v: Vec2,
+
+    /// The frames left before the shot fizzles out.
+    life: u32,
+}
+
+impl Shot {
+    /// Returns a new shot of size `s` centered at `c`, with velocity `v` and lasting `life` frames.
+    pub fn new(c: Vec2, v: Vec2, s: f32, life: u32) -> Self {
+        Self {
+            body: Rect::square(c.x - s / 2.0, c.y - s / 2.0, s),
+            v,
+            life,
+        }
+    }
+
+    /// Returns the shot's body.
+    pub fn get_body(&self) -> Rect {
+        self.body
+    }
+
+    /// Returns true if the shot hasn't hit anything or fizzled out, else false.
+    pub fn is_alive(&self) -> bool {
+        self.life > 0
+    }
+
+    /// Turns the shot towards `p` by `turn`, keeping its speed.
+    pub fn steer(&mut self, p: Vec2, turn: f32) {
+        let c = self.body.center();
+        let speed = self.v.len();
+        if p.x != c.x || p.y != c.y {
+            self.v = (self.v.norm() + (p - c).norm() * turn).norm() * speed;
+        }
+    }
+
+    /// Moves the shot, stopping it at solid tiles and the edges of the window.
+    pub fn update(&mut self, map: TileIter) {
+        self.body.x += self.v.x;
+        self.body.y += self.v.y;
+        self.life = self.life.saturating_sub(1);
+
+        let c = self.body.center();
+        if !WIN_B.contains(c)
+            || map
+                .filter(|(_, t)| t.is_solid())
+                .any(|(b, _)| b.contains(c))
+        {
+            self.life = 0;
+        }
+    }
+
+    /// Draws the shot with the given color.
+    pub fn draw(&self, cnv: &mut Canvas<Window>, color: Color) {
+        cnv.set_draw_color(color);
+        cnv.fill_rect(&self.body).unwrap();
+    }
+}
+
+/// Damages the first enemy each shot touches by `dmg`, removing the shots that hit and the enemies that die.
+pub fn hit_with_shots(shots: &mut Vec<Shot>, e: &mut EnemyVec, dmg: u32) {
+    shots.retain(
+        |s| match e.iter_mut().find(|e| e.get_body().collides_with(&s.body)) {
+            Some(e) => {
+                e.damage(dmg);
+                false
+            }
+            None => true,
+        },
+    );
+    e.retain(|e| e.is_alive());
+}
@@ -0,0 +1,81 @@
+use super::{shot::hit_with_shots, Shot, Weapon};
+use crate::{enemies::EnemyVec, geometry::Vec2, map::TileIter};
+use sdl3::{pixels::Color, render::Canvas, video::Window};
+
+/// A weapon that fires a fan of short-lived pellets.
+pub struct Spread {
+    /// The pellets in flight.
+    shots: Vec<Shot>,
+
+    /// The frames left before the weapon can fire again.
+    cool: u32,
+}
+
+impl Spread {
+    /// Frames between shots.
+    const COOLDOWN: u32 = 30;
+
+    /// Damage dealt by each pellet.
+    const DAMAGE: u32 = 1;
+
+    /// The number of pellets in each shot.
+    const PELLETS: usize = 5;
+
+    /// Angle between pellets, in radians.
+    const ANGLE: f32 = 0.15;
+
+    /// Pellet speed.
+    const SPEED: f32 = 12.0;
+
+    /// Pellet size (width and height).
+    const S: f32 = 6.0;
+
+    /// Frames a pellet lasts for.
+    const LIFE: u32 = 25;
+
+    /// Pellet color.
+    const COLOR: Color = Color {
+        r: 255,
+        g: 200,
+        b: 50,
+        a: 255,
+    };
+
+    /// Returns a new spread weapon, ready to fire.
+    pub fn new() -> Self {
+        Self {
+            shots: Vec::new(),
+            cool: 0,
+        }
+    }
+}
+
+impl Weapon for Spread {
+    fn is_ready(&self) -> bool {
+        self.cool == 0
+    }
+
+    fn fire(&mut self, beg: Vec2, dir: Vec2, _: TileIter, _: f32) {
+        let mid = (Self::PELLETS - 1) as f32 / 2.0;
+        for i in 0..Self::PELLETS {
+            let d = dir.norm().rotate((i as f32 - mid) * Self::ANGLE);
+            self.shots
+                .push(Shot::new(beg, d * Self::SPEED, Self::S, Self::LIFE));
+        }
+        self.cool = Self::COOLDOWN;
+    }
+
+    fn update(&mut self, map: TileIter) {
+        self.cool = self.cool.saturating_sub(1);
+        self.shots.iter_mut().for_each(|s| s.update(map.clone()));
+        self.shots.retain(|s| s.is_alive());
+    }
+
+    fn hit_enemies(&mut self, e: &mut EnemyVec) {
+        hit_with_shots(&mut self.shots, e, Self::DAMAGE);
+    }
+
+    fn draw(&self, cnv: &mut Canvas<Window>) {
+        self.shots.iter().for_each(|s| s.draw(cnv, Self::COLOR));
+    }
+}
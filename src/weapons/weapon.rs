@@ -0,0 +1,80 @@
+use super::{Bolt, Laser, Melee, Spread};
+use crate::{
+    enemies::EnemyVec,
+    geometry::{BBox, Vec2},
+    map::TileIter,
+    tile::TileID,
+};
+use sdl3::{keyboard::Scancode, render::Canvas, video::Window};
+
+/// Something the player can attack enemies with.
+pub trait Weapon {
+    /// Returns true if the weapon's cooldown is over and it can fire, else false.
+    fn is_ready(&self) -> bool;
+
+    /// Returns true if firing the weapon drains energy and can be charged, else false.
+    fn uses_energy(&self) -> bool {
+        false
+    }
+
+    /// Fires the weapon from `beg` in the direction `dir`, which can be of any non-zero length.
+    /// The charge goes from 0 (uncharged) to 1 (fully charged), and is always 0 for weapons that don't use energy.
+    fn fire(&mut self, beg: Vec2, dir: Vec2, map: TileIter, charge: f32);
+
+    /// Updates the weapon's cooldown and anything it has fired.
+    fn update(&mut self, map: TileIter);
+
+    /// Damages the enemies hit by the weapon, removing the ones that die.
+    fn hit_enemies(&mut self, e: &mut EnemyVec);
+
+    /// Returns the tile the weapon just activated and the outwards normal of the side it hit, only once.
+    fn take_activated(&mut self) -> Option<(BBox, TileID, Vec2)> {
+        None
+    }
+
+    /// Sets whether the weapon passes through enemies, if it's able to.
+    fn set_pierce(&mut self, _: bool) {}
+
+    /// Draws anything the weapon has fired.
+    fn draw(&self, cnv: &mut Canvas<Window>);
+}
+
+/// The kinds of weapons, in the order of their number keys.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WeaponID {
+    Laser,  // 1
+    Spread, // 2
+    Bolt,   // 3
+    Melee,  // 4
+}
+
+impl WeaponID {
+    /// The number of kinds of weapons.
+    pub const N: usize = 4;
+
+    /// All kinds of weapons, in the order of their number keys.
+    pub const ALL: [WeaponID; Self::N] = [Self::Laser, Self::Spread, Self::Bolt, Self::Melee];
+
+    /// The number keys used to switch weapons.
+    pub const KEYS: [Scancode; Self::N] = [Scancode::_1, Scancode::_2, Scancode::_3, Scancode::_4];
+
+    /// Returns the weapon's name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Laser => "LASER",
+            Self::Spread => "SPREAD",
+            Self::Bolt => "BOLT",
+            Self::Melee => "MELEE",
+        }
+    }
+
+    /// Returns a new weapon of this kind.
+    pub fn make(self) -> Box<dyn Weapon> {
+        match self {
+            Self::Laser => Box::new(Laser::new_inactive()),
+            Self::Spread => Box::new(Spread::new()),
+            Self::Bolt => Box::new(Bolt::new()),
+            Self::Melee => Box::new(Melee::new()),
+        }
+    }
+}
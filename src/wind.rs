@@ -0,0 +1,108 @@
+use super::{
+    entity::Entity,
+    geometry::{BBox, Vec2},
+};
+use sdl3::{
+    pixels::Color,
+    render::{Canvas, FPoint},
+    video::Window,
+};
+
+/// An area that continuously pushes entities inside it, like an updraft or a fan.
+///
+/// Wind only adds speed until its max is reached, so it never slows down entities launched by pads.
+/// It is applied before entities update, so it can carry the player past its own max walking speed.
+#[derive(Clone, Copy, Debug)]
+pub struct Wind {
+    /// The area the wind blows in.
+    bbox: BBox,
+
+    /// The velocity added to entities every frame.
+    force: Vec2,
+
+    /// The speed along the force that the wind can push entities up to.
+    max: f32,
+
+    /// How many frames the wind blows for in every cycle.
+    on: u32,
+
+    /// How many frames the wind stops for in every cycle (zero for always blowing).
+    off: u32,
+}
+
+impl Wind {
+    /// Number of flow particles drawn per wind.
+    const PARTICLES: u32 = 24;
+
+    /// Length of the flow particle streaks.
+    const STREAK: f32 = 8.0;
+
+    /// Returns a new wind that always blows.
+    pub const fn new(bbox: BBox, force: Vec2, max: f32) -> Self {
+        Self::fan(bbox, force, max, 1, 0)
+    }
+
+    /// Returns a new wind that blows for `on` frames, then stops for `off` frames.
+    pub const fn fan(bbox: BBox, force: Vec2, max: f32, on: u32, off: u32) -> Self {
+        Self {
+            bbox,
+            force,
+            max,
+            on,
+            off,
+        }
+    }
+
+    /// Returns true if the wind is blowing at frame `t`, else false.
+    pub fn is_on(&self, t: u32) -> bool {
+        self.off == 0 || t % (self.on + self.off) < self.on
+    }
+
+    /// Pushes the entity if it is inside the wind and the wind is blowing at frame `t`.
+    pub fn push(&self, t: u32, e: &mut dyn Entity) {
+        if self.is_on(t) && self.bbox.collides_with_rect(&e.get_body()) {
+            e.push(self.force, self.max);
+        }
+    }
+
+    /// Draws the wind's area and, if blowing at frame `t`, particles moving with the flow.
+    pub fn draw(&self, t: u32, cnv: &mut Canvas<Window>) {
+        let b = self.bbox;
+        cnv.set_draw_color(Color {
+            r: 255,
+            g: 255,
+            b: 255,
+            a: 15,
+        });
+        cnv.fill_rect(&b).unwrap();
+
+        if !self.is_on(t) {
+            return;
+        }
+
+        // Particles travel at the wind's max speed from spread out positions, wrapping around the area.
+        let (w, h) = (b.a - b.x, b.b - b.y);
+        let dir = self.force.norm();
+        let moved = dir * (self.max * t as f32);
+
+        cnv.set_draw_color(Color {
+            r: 255,
+            g: 255,
+            b: 255,
+            a: 90,
+        });
+        for i in 0..Self::PARTICLES {
+            let p = Vec2::new(
+                (i as f32 * 0.618_034).fract() * w + moved.x,
+                (i as f32 * 0.414_214).fract() * h + moved.y,
+            );
+            let p = Vec2::new(b.x + p.x.rem_euclid(w), b.y + p.y.rem_euclid(h));
+            let tail = p - dir * Self::STREAK;
+
+            // Skip streaks that would be cut by wrapping.
+            if b.contains(tail) {
+                cnv.draw_line(FPoint::from(tail), FPoint::from(p)).unwrap();
+            }
+        }
+    }
+}